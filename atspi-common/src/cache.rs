@@ -1,7 +1,7 @@
 //! Common types for `org.a11y.atspi.Cache` events.
 //!
 
-use crate::{InterfaceSet, ObjectRef, Role, StateSet};
+use crate::{AtspiError, Interface, InterfaceSet, ObjectRef, Role, StateSet};
 use serde::{Deserialize, Serialize};
 use zbus_lockstep_macros::validate;
 use zbus_names::UniqueName;
@@ -66,6 +66,38 @@ impl Default for CacheItem {
 	}
 }
 
+impl CacheItem {
+	/// The interfaces the accessible implements.
+	#[must_use]
+	pub fn interfaces(&self) -> InterfaceSet {
+		self.ifaces
+	}
+
+	/// Whether the accessible implements `iface`.
+	#[must_use]
+	pub fn supports(&self, iface: Interface) -> bool {
+		self.ifaces.contains(iface)
+	}
+
+	/// The [`ObjectRef`] of the accessible this item describes.
+	#[must_use]
+	pub fn to_object_ref(&self) -> ObjectRef {
+		self.object.clone()
+	}
+}
+
+impl From<CacheItem> for ObjectRef {
+	fn from(item: CacheItem) -> Self {
+		item.object
+	}
+}
+
+impl From<&CacheItem> for ObjectRef {
+	fn from(item: &CacheItem) -> Self {
+		item.to_object_ref()
+	}
+}
+
 /// The item type provided by `Cache:Add` signals
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug, Serialize, Deserialize, Type, PartialEq, Eq, Hash)]
@@ -120,6 +152,59 @@ impl Default for LegacyCacheItem {
 	}
 }
 
+impl From<LegacyCacheItem> for CacheItem {
+	/// Upgrades a legacy item.
+	///
+	/// The legacy item does not carry the index in parent, so `index` is `-1`, as for an object without parent.
+	/// `children` is the length of the list of child references.
+	fn from(item: LegacyCacheItem) -> Self {
+		Self {
+			object: item.object,
+			app: item.app,
+			parent: item.parent,
+			index: -1,
+			children: i32::try_from(item.children.len()).unwrap_or(i32::MAX),
+			ifaces: item.ifaces,
+			short_name: item.short_name,
+			role: item.role,
+			name: item.name,
+			states: item.states,
+		}
+	}
+}
+
+impl TryFrom<CacheItem> for LegacyCacheItem {
+	type Error = AtspiError;
+
+	/// Downgrades an item for registries which only understand the legacy format.
+	///
+	/// The index in parent is lost.
+	/// The item only carries a child count, while the legacy format lists the children,
+	/// so only items without children can be downgraded.
+	///
+	/// # Errors
+	///
+	/// If the item has any children.
+	fn try_from(item: CacheItem) -> Result<Self, Self::Error> {
+		if item.children != 0 {
+			return Err(AtspiError::Conversion(
+				"a `CacheItem` with children cannot list them as a `LegacyCacheItem`",
+			));
+		}
+		Ok(Self {
+			object: item.object,
+			app: item.app,
+			parent: item.parent,
+			children: Vec::new(),
+			ifaces: item.ifaces,
+			short_name: item.short_name,
+			role: item.role,
+			name: item.name,
+			states: item.states,
+		})
+	}
+}
+
 #[cfg(test)]
 #[test]
 fn zvariant_type_signature_of_legacy_cache_item() {
@@ -128,3 +213,66 @@ fn zvariant_type_signature_of_legacy_cache_item() {
 		zbus::zvariant::Signature::from_static_str("((so)(so)(so)a(so)assusau)").unwrap()
 	);
 }
+
+#[cfg(test)]
+#[test]
+fn cache_item_supports_interfaces() {
+	let ifaces = InterfaceSet::new(Interface::Accessible | Interface::Component);
+	let item = CacheItem { ifaces, ..CacheItem::default() };
+	assert_eq!(item.interfaces(), ifaces);
+	assert!(item.supports(Interface::Accessible));
+	assert!(item.supports(Interface::Component));
+	assert!(!item.supports(Interface::Text));
+
+	let empty = CacheItem::default();
+	assert!(empty.interfaces().iter().next().is_none());
+	assert!(!empty.supports(Interface::Accessible));
+}
+
+#[cfg(test)]
+#[test]
+fn cache_item_to_object_ref() {
+	let object = ObjectRef {
+		name: UniqueName::from_static_str(":1.42").unwrap().into(),
+		path: ObjectPath::from_static_str("/org/a11y/atspi/accessible/7")
+			.unwrap()
+			.into(),
+	};
+	let item = CacheItem { object: object.clone(), ..CacheItem::default() };
+	assert_eq!(item.to_object_ref().name.as_str(), ":1.42");
+	assert_eq!(item.to_object_ref().path.as_str(), "/org/a11y/atspi/accessible/7");
+	assert_eq!(ObjectRef::from(&item), object);
+	assert_eq!(ObjectRef::from(item), object);
+}
+
+#[cfg(test)]
+#[test]
+fn legacy_cache_item_round_trip() {
+	use crate::State;
+
+	let legacy = LegacyCacheItem {
+		ifaces: InterfaceSet::new(Interface::Accessible | Interface::Action),
+		short_name: "OK".to_string(),
+		role: Role::Button,
+		name: "Confirm".to_string(),
+		states: StateSet::new(State::Focusable | State::Showing),
+		..LegacyCacheItem::default()
+	};
+	let item = CacheItem::from(legacy.clone());
+	assert_eq!(item.index, -1);
+	assert_eq!(item.children, 0);
+	assert_eq!(item.role, Role::Button);
+	assert_eq!(LegacyCacheItem::try_from(item).unwrap(), legacy);
+}
+
+#[cfg(test)]
+#[test]
+fn cache_item_with_children_cannot_be_downgraded() {
+	let legacy = LegacyCacheItem {
+		children: vec![ObjectRef::default(), ObjectRef::default()],
+		..LegacyCacheItem::default()
+	};
+	let item = CacheItem::from(legacy);
+	assert_eq!(item.children, 2);
+	assert!(LegacyCacheItem::try_from(item).is_err());
+}
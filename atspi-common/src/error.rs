@@ -39,6 +39,15 @@ pub enum AtspiError {
 	/// When matching on an unknown role
 	UnknownRole(u32),
 
+	/// When converting a number which is not a known state.
+	UnknownState(u32),
+
+	/// When converting a number which is not a known relation type.
+	UnknownRelation(u32),
+
+	/// When converting a number which is not a known layer.
+	UnknownLayer(u32),
+
 	/// No name on bus.
 	MissingName,
 
@@ -51,6 +60,19 @@ pub enum AtspiError {
 	/// A `zbus` or `zbus::Fdo` error. variant.
 	Zbus(String),
 
+	/// A `zbus` or `zbus::Fdo` error caused by the connection to the bus being lost.
+	/// See [`AtspiError::is_dbus_disconnect`].
+	ZbusDisconnected(String),
+
+	/// A `zbus` or `zbus::Fdo` error caused by a method call timing out, or receiving no reply.
+	ZbusTimedOut(String),
+
+	/// A `zbus` or `zbus::Fdo` error caused by the bus refusing the connection.
+	ZbusConnectionRefused(String),
+
+	/// A `zbus` or `zbus::Fdo` error caused by an unknown destination, object, interface, method or property.
+	ZbusNotFound(String),
+
 	/// A `zbus_names` error variant
 	ZBusNames(zbus_names::Error),
 
@@ -75,6 +97,38 @@ pub enum AtspiError {
 
 impl std::error::Error for AtspiError {}
 
+impl AtspiError {
+	/// Whether the error is caused by the connection to the bus being lost,
+	/// as opposed to an error in the exchange itself.
+	///
+	/// Such errors are usually transient: reconnecting and retrying may succeed.
+	///
+	/// `zbus` errors are classified when they are converted into an [`AtspiError`]:
+	/// those caused by a disconnect become [`AtspiError::ZbusDisconnected`].
+	#[must_use]
+	pub fn is_dbus_disconnect(&self) -> bool {
+		match self {
+			Self::IO(e) => is_disconnect_kind(e.kind()),
+			Self::ZbusDisconnected(_) => true,
+			_ => false,
+		}
+	}
+}
+
+/// Whether an I/O error of this kind means the connection is lost.
+fn is_disconnect_kind(kind: std::io::ErrorKind) -> bool {
+	use std::io::ErrorKind;
+
+	matches!(
+		kind,
+		ErrorKind::BrokenPipe
+			| ErrorKind::ConnectionReset
+			| ErrorKind::ConnectionAborted
+			| ErrorKind::NotConnected
+			| ErrorKind::UnexpectedEof
+	)
+}
+
 impl std::fmt::Display for AtspiError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
@@ -104,6 +158,18 @@ impl std::fmt::Display for AtspiError {
 				f.write_str("atspi: Unknown role: ")?;
 				e.fmt(f)
 			}
+			Self::UnknownState(e) => {
+				f.write_str("atspi: Unknown state: ")?;
+				e.fmt(f)
+			}
+			Self::UnknownRelation(e) => {
+				f.write_str("atspi: Unknown relation type: ")?;
+				e.fmt(f)
+			}
+			Self::UnknownLayer(e) => {
+				f.write_str("atspi: Unknown layer: ")?;
+				e.fmt(f)
+			}
 			Self::UnknownSignal => f.write_str("atspi: Unknown signal"),
 			Self::CacheVariantMismatch => f.write_str("atspi: Cache variant mismatch"),
 			Self::Owned(e) => {
@@ -114,6 +180,22 @@ impl std::fmt::Display for AtspiError {
 				f.write_str("ZBus Error: ")?;
 				e.fmt(f)
 			}
+			Self::ZbusDisconnected(e) => {
+				f.write_str("ZBus Error, disconnected: ")?;
+				e.fmt(f)
+			}
+			Self::ZbusTimedOut(e) => {
+				f.write_str("ZBus Error, timed out: ")?;
+				e.fmt(f)
+			}
+			Self::ZbusConnectionRefused(e) => {
+				f.write_str("ZBus Error, connection refused: ")?;
+				e.fmt(f)
+			}
+			Self::ZbusNotFound(e) => {
+				f.write_str("ZBus Error, not found: ")?;
+				e.fmt(f)
+			}
 			Self::Zvariant(e) => {
 				f.write_str("Zvariant error: ")?;
 				e.fmt(f)
@@ -157,14 +239,70 @@ impl From<std::num::TryFromIntError> for AtspiError {
 #[cfg(feature = "zbus")]
 impl From<zbus::fdo::Error> for AtspiError {
 	fn from(e: zbus::fdo::Error) -> Self {
-		Self::Zbus(format!("{e:?}"))
+		classify_zbus(fdo_error_kind(&e), format!("{e:?}"))
 	}
 }
 
 #[cfg(feature = "zbus")]
 impl From<zbus::Error> for AtspiError {
 	fn from(e: zbus::Error) -> Self {
-		Self::Zbus(format!("{e:?}"))
+		classify_zbus(zbus_error_kind(&e), format!("{e:?}"))
+	}
+}
+
+/// Picks the `zbus` variant for an error of `kind`, see [`zbus_error_kind`].
+#[cfg(feature = "zbus")]
+fn classify_zbus(kind: Option<std::io::ErrorKind>, msg: String) -> AtspiError {
+	use std::io::ErrorKind;
+
+	match kind {
+		Some(kind) if is_disconnect_kind(kind) => AtspiError::ZbusDisconnected(msg),
+		Some(ErrorKind::TimedOut) => AtspiError::ZbusTimedOut(msg),
+		Some(ErrorKind::ConnectionRefused) => AtspiError::ZbusConnectionRefused(msg),
+		Some(ErrorKind::NotFound) => AtspiError::ZbusNotFound(msg),
+		_ => AtspiError::Zbus(msg),
+	}
+}
+
+/// The kind of I/O failure behind a `zbus` error, if known.
+///
+/// Only the error's structure is looked at: the kind of an I/O error, or the name of a D-Bus error.
+/// Messages are ignored, as they may come from the remote application.
+#[cfg(feature = "zbus")]
+fn zbus_error_kind(e: &zbus::Error) -> Option<std::io::ErrorKind> {
+	match e {
+		zbus::Error::InputOutput(e) => Some(e.kind()),
+		zbus::Error::MethodError(name, _, _) => dbus_error_kind(name.as_str()),
+		zbus::Error::FDO(e) => fdo_error_kind(e),
+		_ => None,
+	}
+}
+
+#[cfg(feature = "zbus")]
+fn fdo_error_kind(e: &zbus::fdo::Error) -> Option<std::io::ErrorKind> {
+	match e {
+		zbus::fdo::Error::ZBus(e) => zbus_error_kind(e),
+		e => dbus_error_kind(zbus::DBusError::name(e).as_str()),
+	}
+}
+
+/// The kind of I/O failure a well-known D-Bus error `name` stands for, if any.
+#[cfg(feature = "zbus")]
+fn dbus_error_kind(name: &str) -> Option<std::io::ErrorKind> {
+	use std::io::ErrorKind;
+
+	match name {
+		"org.freedesktop.DBus.Error.Disconnected" => Some(ErrorKind::NotConnected),
+		"org.freedesktop.DBus.Error.Timeout"
+		| "org.freedesktop.DBus.Error.TimedOut"
+		| "org.freedesktop.DBus.Error.NoReply" => Some(ErrorKind::TimedOut),
+		"org.freedesktop.DBus.Error.ServiceUnknown"
+		| "org.freedesktop.DBus.Error.NameHasNoOwner"
+		| "org.freedesktop.DBus.Error.UnknownObject"
+		| "org.freedesktop.DBus.Error.UnknownInterface"
+		| "org.freedesktop.DBus.Error.UnknownMethod"
+		| "org.freedesktop.DBus.Error.UnknownProperty" => Some(ErrorKind::NotFound),
+		_ => None,
 	}
 }
 
@@ -192,6 +330,65 @@ impl From<ObjectPathConversionError> for AtspiError {
 	}
 }
 
+/// Convert an [`AtspiError`] into a [`std::io::Error`], for use in APIs built around [`std::io::Result`].
+///
+/// The [`std::io::ErrorKind`] is chosen as follows:
+///
+/// - [`AtspiError::IO`] yields the wrapped error as-is.
+/// - [`AtspiError::ZbusTimedOut`] yields [`TimedOut`], [`AtspiError::ZbusConnectionRefused`]
+///   yields [`ConnectionRefused`], [`AtspiError::ZbusNotFound`] yields [`NotFound`],
+///   [`AtspiError::ZbusDisconnected`] yields [`NotConnected`], and [`AtspiError::Zbus`] yields [`Other`].
+///   `zbus` errors are sorted into these variants when they are converted into an [`AtspiError`].
+/// - Missing or unknown names, interfaces, members, roles and signals yield [`NotFound`].
+/// - Failed conversions, parses and mismatches yield [`InvalidData`].
+/// - All remaining variants yield [`Other`].
+///
+/// The original [`AtspiError`] is kept as the source of the resulting error.
+///
+/// [`TimedOut`]: std::io::ErrorKind::TimedOut
+/// [`ConnectionRefused`]: std::io::ErrorKind::ConnectionRefused
+/// [`NotFound`]: std::io::ErrorKind::NotFound
+/// [`NotConnected`]: std::io::ErrorKind::NotConnected
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`Other`]: std::io::ErrorKind::Other
+impl From<AtspiError> for std::io::Error {
+	fn from(e: AtspiError) -> Self {
+		use std::io::ErrorKind;
+
+		let kind = match e {
+			AtspiError::IO(io) => return io,
+			AtspiError::ZbusTimedOut(_) => ErrorKind::TimedOut,
+			AtspiError::ZbusConnectionRefused(_) => ErrorKind::ConnectionRefused,
+			AtspiError::ZbusDisconnected(_) => ErrorKind::NotConnected,
+			AtspiError::ZbusNotFound(_)
+			| AtspiError::InterfaceNotAvailable(_)
+			| AtspiError::UnknownInterface
+			| AtspiError::MissingInterface
+			| AtspiError::MissingMember
+			| AtspiError::MissingSignature
+			| AtspiError::MissingName
+			| AtspiError::UnknownRole(_)
+			| AtspiError::UnknownState(_)
+			| AtspiError::UnknownRelation(_)
+			| AtspiError::UnknownLayer(_)
+			| AtspiError::UnknownSignal => ErrorKind::NotFound,
+			AtspiError::Conversion(_)
+			| AtspiError::CacheVariantMismatch
+			| AtspiError::MemberMatch(_)
+			| AtspiError::InterfaceMatch(_)
+			| AtspiError::KindMatch(_)
+			| AtspiError::SignatureMatch(_)
+			| AtspiError::ZBusNames(_)
+			| AtspiError::Zvariant(_)
+			| AtspiError::ParseError(_)
+			| AtspiError::PathConversionError(_)
+			| AtspiError::IntConversionError(_) => ErrorKind::InvalidData,
+			AtspiError::Zbus(_) | AtspiError::Owned(_) | AtspiError::Infallible => ErrorKind::Other,
+		};
+		std::io::Error::new(kind, e)
+	}
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug)]
 pub enum ObjectPathConversionError {
@@ -210,3 +407,82 @@ impl std::fmt::Display for ObjectPathConversionError {
 	}
 }
 impl std::error::Error for ObjectPathConversionError {}
+
+#[cfg(test)]
+mod tests {
+	use super::AtspiError;
+	use std::io::ErrorKind;
+
+	#[test]
+	fn io_error_is_passed_through() {
+		let err = AtspiError::IO(std::io::Error::new(ErrorKind::BrokenPipe, "pipe"));
+		assert_eq!(std::io::Error::from(err).kind(), ErrorKind::BrokenPipe);
+	}
+
+	/// The [`ErrorKind`] of `e` once converted into an [`AtspiError`], then into an I/O error.
+	#[cfg(feature = "zbus")]
+	fn io_kind(e: impl Into<AtspiError>) -> ErrorKind {
+		std::io::Error::from(e.into()).kind()
+	}
+
+	#[cfg(feature = "zbus")]
+	#[test]
+	fn zbus_errors_map_to_io_error_kinds() {
+		let timeout = zbus::fdo::Error::TimedOut("Method call timed out".to_string());
+		assert_eq!(io_kind(timeout), ErrorKind::TimedOut);
+		let no_reply = zbus::Error::FDO(Box::new(zbus::fdo::Error::NoReply("gone".to_string())));
+		assert_eq!(io_kind(no_reply), ErrorKind::TimedOut);
+
+		let refused = std::io::Error::new(ErrorKind::ConnectionRefused, "Connection refused");
+		assert_eq!(io_kind(zbus::Error::InputOutput(refused.into())), ErrorKind::ConnectionRefused);
+
+		let unknown = zbus::fdo::Error::ServiceUnknown("no such name".to_string());
+		assert_eq!(io_kind(unknown), ErrorKind::NotFound);
+		let method =
+			zbus::names::OwnedErrorName::try_from("org.freedesktop.DBus.Error.UnknownMethod")
+				.unwrap();
+		let method = zbus::Error::MethodError(
+			method,
+			None,
+			zbus::Message::method("/", "Ping").unwrap().build(&()).unwrap(),
+		);
+		assert_eq!(io_kind(method), ErrorKind::NotFound);
+	}
+
+	#[cfg(feature = "zbus")]
+	#[test]
+	fn remote_messages_do_not_pick_the_io_error_kind() {
+		let failed = zbus::fdo::Error::Failed("Timeout: NoReply from UnknownMethod".to_string());
+		assert_eq!(io_kind(failed), ErrorKind::Other);
+		let zbus_error = AtspiError::Zbus("FDO(TimedOut(\"Method call timed out\"))".to_string());
+		assert_eq!(std::io::Error::from(zbus_error).kind(), ErrorKind::Other);
+	}
+
+	#[test]
+	fn atspi_errors_map_to_io_error_kinds() {
+		assert_eq!(std::io::Error::from(AtspiError::MissingName).kind(), ErrorKind::NotFound);
+		assert_eq!(
+			std::io::Error::from(AtspiError::Conversion("bad")).kind(),
+			ErrorKind::InvalidData
+		);
+		assert_eq!(std::io::Error::from(AtspiError::Owned("x".into())).kind(), ErrorKind::Other);
+	}
+
+	#[cfg(feature = "zbus")]
+	#[test]
+	fn disconnects_are_recognized() {
+		let closed = std::io::Error::new(ErrorKind::BrokenPipe, "socket closed");
+		let closed = zbus::Error::InputOutput(std::sync::Arc::new(closed));
+		assert!(AtspiError::from(closed).is_dbus_disconnect());
+		let closed = zbus::fdo::Error::Disconnected("bus went away".to_string());
+		assert!(AtspiError::from(closed).is_dbus_disconnect());
+		let eof = std::io::Error::new(ErrorKind::UnexpectedEof, "eof");
+		assert!(AtspiError::IO(eof).is_dbus_disconnect());
+
+		let parse = zbus::Error::Variant(zvariant::Error::IncorrectType);
+		assert!(!AtspiError::from(parse).is_dbus_disconnect());
+		let remote = zbus::fdo::Error::Failed("Disconnected: BrokenPipe".to_string());
+		assert!(!AtspiError::from(remote).is_dbus_disconnect());
+		assert!(!AtspiError::ParseError("not a role").is_dbus_disconnect());
+	}
+}
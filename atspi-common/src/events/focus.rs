@@ -10,6 +10,10 @@ use crate::{
 use zbus_names::UniqueName;
 use zvariant::ObjectPath;
 
+/// Events of the deprecated `org.a11y.atspi.Event.Focus` interface.
+///
+/// Toolkits should report focus changes with [`crate::events::object::StateChangedEvent`] instead,
+/// but some still emit these, so they are parsed like any other event.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum FocusEvents {
 	/// See: [`FocusEvent`].
@@ -61,6 +65,9 @@ impl HasMatchRule for FocusEvents {
 	const MATCH_RULE_STRING: &'static str = "type='signal',interface='org.a11y.atspi.Event.Focus'";
 }
 
+/// The object `item` received focus.
+///
+/// This is the only event of the deprecated Focus interface; see [`FocusEvents`].
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct FocusEvent {
 	/// The [`crate::ObjectRef`] which the event applies to.
@@ -117,3 +124,27 @@ impl_from_object_ref!(FocusEvent);
 impl HasRegistryEventString for FocusEvents {
 	const REGISTRY_EVENT_STRING: &'static str = "Focus:";
 }
+
+#[cfg(all(test, feature = "zbus"))]
+mod tests {
+	use super::{FocusEvent, FocusEvents};
+	use crate::{events::ObjectRef, Event};
+
+	#[test]
+	fn focus_event_round_trips_through_message() {
+		let event = FocusEvent {
+			item: ObjectRef {
+				name: ":1.23".try_into().unwrap(),
+				path: "/org/a11y/atspi/accessible/7".try_into().unwrap(),
+			},
+		};
+		let msg = zbus::Message::try_from(event.clone()).unwrap();
+		assert_eq!(msg.header().interface().unwrap().as_str(), "org.a11y.atspi.Event.Focus");
+		assert_eq!(msg.header().member().unwrap().as_str(), "Focus");
+
+		assert_eq!(FocusEvent::try_from(&msg).unwrap(), event);
+		let parsed = Event::try_from(&msg).unwrap();
+		assert_eq!(parsed, Event::Focus(FocusEvents::Focus(event.clone())));
+		assert_eq!(FocusEvent::try_from(parsed).unwrap(), event);
+	}
+}
@@ -26,6 +26,9 @@ pub const CACHE_ADD_SIGNATURE: Signature<'_> =
 	Signature::from_static_str_unchecked("((so)(so)(so)iiassusau)");
 
 use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::OnceLock;
+use std::time::{Instant, SystemTime};
 
 use serde::{Deserialize, Serialize};
 use zbus_lockstep_macros::validate;
@@ -181,6 +184,53 @@ impl Clone for EventBodyOwned {
 	}
 }
 
+/// Standard event body, borrowing its data from the message it is deserialized from.
+///
+/// Deserializing this instead of [`EventBodyOwned`] avoids allocating for the `kind` string,
+/// for string `any_data`, and, in the common case of an empty `properties` map, for the map.
+/// Use [`EventBodyOwned::try_from`] to keep the body beyond the lifetime of the message.
+///
+/// Signature `(siiva{sv})`,
+#[validate(signal: "PropertyChange")]
+#[derive(Debug, Serialize, Deserialize, Type, PartialEq)]
+pub struct EventBodyBorrowed<'a> {
+	/// kind variant, used for specifying an event triple "object:state-changed:focused",
+	/// the "focus" part of this event is what is contained within the kind.
+	#[serde(rename = "type")]
+	pub kind: &'a str,
+	/// Generic detail1 value described by AT-SPI.
+	pub detail1: i32,
+	/// Generic detail2 value described by AT-SPI.
+	pub detail2: i32,
+	/// Generic `any_data` value described by AT-SPI.
+	/// This can be any type.
+	#[serde(borrow)]
+	pub any_data: Value<'a>,
+	/// A map of properties.
+	/// Not in use.
+	#[serde(borrow)]
+	pub properties: HashMap<UniqueName<'a>, Value<'a>>,
+}
+
+impl TryFrom<EventBodyBorrowed<'_>> for EventBodyOwned {
+	type Error = AtspiError;
+
+	fn try_from(body: EventBodyBorrowed<'_>) -> Result<Self, Self::Error> {
+		let properties = body
+			.properties
+			.into_iter()
+			.map(|(name, value)| Ok((name.into(), value.try_to_owned()?)))
+			.collect::<Result<_, AtspiError>>()?;
+		Ok(Self {
+			kind: body.kind.to_owned(),
+			detail1: body.detail1,
+			detail2: body.detail2,
+			any_data: body.any_data.try_to_owned()?,
+			properties,
+		})
+	}
+}
+
 /// Encapsulates the various different accessibility bus signal types.
 ///
 /// Assumes being non exhaustive to allow for future- or custom signals.
@@ -299,6 +349,113 @@ impl EventProperties for Event {
 	}
 }
 
+impl Event {
+	/// The bus name of the application which sent the event.
+	///
+	/// Useful to group events by application without matching on the variant,
+	/// or importing [`EventProperties`].
+	#[must_use]
+	pub fn sender(&self) -> UniqueName<'_> {
+		EventProperties::sender(self)
+	}
+
+	/// The object path of the accessible the event applies to.
+	///
+	/// Together with [`Event::sender`], this identifies the object in a cached tree.
+	#[must_use]
+	pub fn path(&self) -> ObjectPath<'_> {
+		EventProperties::path(self)
+	}
+
+	/// The event string to pass to the registry to receive events like this one, e.g. `"Object:"`.
+	///
+	/// The runtime counterpart of [`registry_string_of`], for when the event type is only known at runtime.
+	#[must_use]
+	pub fn registry_string(&self) -> &'static str {
+		EventTypeProperties::registry_string(self)
+	}
+
+	/// The match rule which selects events like this one, e.g. all `Object:StateChanged` signals.
+	///
+	/// The runtime counterpart of [`match_rule_of`], for when the event type is only known at runtime.
+	#[must_use]
+	pub fn match_rule_string(&self) -> &'static str {
+		EventTypeProperties::match_rule(self)
+	}
+
+	/// The [`ObjectEvents`], if this is an `Event::Object`.
+	#[must_use]
+	pub fn as_object(&self) -> Option<&ObjectEvents> {
+		match self {
+			Self::Object(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`WindowEvents`], if this is an `Event::Window`.
+	#[must_use]
+	pub fn as_window(&self) -> Option<&WindowEvents> {
+		match self {
+			Self::Window(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`MouseEvents`], if this is an `Event::Mouse`.
+	#[must_use]
+	pub fn as_mouse(&self) -> Option<&MouseEvents> {
+		match self {
+			Self::Mouse(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`KeyboardEvents`], if this is an `Event::Keyboard`.
+	#[must_use]
+	pub fn as_keyboard(&self) -> Option<&KeyboardEvents> {
+		match self {
+			Self::Keyboard(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`DocumentEvents`], if this is an `Event::Document`.
+	#[must_use]
+	pub fn as_document(&self) -> Option<&DocumentEvents> {
+		match self {
+			Self::Document(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`TerminalEvents`], if this is an `Event::Terminal`.
+	#[must_use]
+	pub fn as_terminal(&self) -> Option<&TerminalEvents> {
+		match self {
+			Self::Terminal(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`FocusEvents`], if this is an `Event::Focus`.
+	#[must_use]
+	pub fn as_focus(&self) -> Option<&FocusEvents> {
+		match self {
+			Self::Focus(inner) => Some(inner),
+			_ => None,
+		}
+	}
+
+	/// The [`CacheEvents`], if this is an `Event::Cache`.
+	#[must_use]
+	pub fn as_cache(&self) -> Option<&CacheEvents> {
+		match self {
+			Self::Cache(inner) => Some(inner),
+			_ => None,
+		}
+	}
+}
+
 impl HasInterfaceName for EventListenerEvents {
 	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Registry";
 }
@@ -615,8 +772,20 @@ impl_to_dbus_message!(EventListenerRegisteredEvent);
 pub struct AvailableEvent {
 	/// The [`ObjectRef`] the event applies to.
 	pub item: ObjectRef,
+	/// The root accessible of the registry: the entry point into the accessibility tree.
 	pub socket: ObjectRef,
 }
+
+impl AvailableEvent {
+	/// The [`ObjectRef`] of the registry's root accessible.
+	///
+	/// This is the object through which all applications on the accessibility bus can be reached.
+	/// Receiving this event means the registry (re)started; any cached tree should be reseeded from here.
+	#[must_use]
+	pub fn socket(&self) -> &ObjectRef {
+		&self.socket
+	}
+}
 impl From<AvailableEvent> for Event {
 	fn from(ev: AvailableEvent) -> Event {
 		Event::Available(ev)
@@ -712,6 +881,152 @@ impl TryFrom<&zbus::Message> for Event {
 	}
 }
 
+#[cfg(feature = "zbus")]
+impl Event {
+	/// Parses an event from a complete D-Bus message as sent over the wire, e.g. the bytes of [`zbus::Message::data`].
+	///
+	/// This is meant for replaying recorded events, and for fuzzing.
+	/// The message is rebuilt from its header fields and body, then converted like a received message.
+	/// File descriptors are not supported, as no event carries any.
+	///
+	/// # Errors
+	///
+	/// - If `bytes` is not a complete D-Bus signal.
+	/// - If the message is not a known event, as for `Event::try_from(&zbus::Message)`.
+	pub fn try_from_bytes(bytes: &[u8]) -> Result<Event, AtspiError> {
+		use zvariant::serialized::{Context, Data};
+		use zvariant::{Endian, Structure};
+
+		/// Header field codes, as defined by the D-Bus specification.
+		const PATH: u8 = 1;
+		const INTERFACE: u8 = 2;
+		const MEMBER: u8 = 3;
+		const SENDER: u8 = 7;
+		const SIGNATURE: u8 = 8;
+		const SIGNAL: u8 = 4;
+		/// Endianness, message type, flags, protocol version, body length, serial and header fields.
+		type RawHeader = (u8, u8, u8, u8, u32, u32, Vec<(u8, OwnedValue)>);
+
+		let endian = match bytes.first() {
+			Some(b'l') => Endian::Little,
+			Some(b'B') => Endian::Big,
+			_ => return Err(AtspiError::Conversion("not a D-Bus message")),
+		};
+		let data = Data::new(bytes, Context::new_dbus(endian, 0));
+		let ((_, msg_type, _, _, body_len, _, fields), header_len): (RawHeader, usize) =
+			data.deserialize()?;
+		if msg_type != SIGNAL {
+			return Err(AtspiError::Conversion("D-Bus message is not a signal"));
+		}
+		let field = |code: u8| fields.iter().find(|(c, _)| *c == code).map(|(_, value)| value);
+		let string_field = |code: u8| -> Result<Option<&str>, AtspiError> {
+			field(code)
+				.map(|value| value.downcast_ref::<&str>())
+				.transpose()
+				.map_err(Into::into)
+		};
+		let path = field(PATH)
+			.ok_or(AtspiError::Conversion("D-Bus signal has no path"))?
+			.downcast_ref::<ObjectPath>()?;
+		let interface = string_field(INTERFACE)?.ok_or(AtspiError::MissingInterface)?;
+		let member = string_field(MEMBER)?.ok_or(AtspiError::MissingMember)?;
+
+		let mut builder = zbus::Message::signal(path, interface, member)?;
+		if let Some(sender) = string_field(SENDER)? {
+			builder = builder.sender(sender)?;
+		}
+		// The body is aligned to 8 bytes.
+		let body_start = header_len.next_multiple_of(8);
+		let body_end = body_start
+			.checked_add(usize::try_from(body_len)?)
+			.filter(|&body_end| body_end <= bytes.len())
+			.ok_or(AtspiError::Conversion("D-Bus message is truncated"))?;
+		let msg = match field(SIGNATURE) {
+			Some(signature) => {
+				let signature = format!("({})", signature.downcast_ref::<Signature>()?);
+				let body = data.slice(body_start..body_end);
+				let (body, _): (Structure, usize) =
+					body.deserialize_for_dynamic_signature(signature.as_str())?;
+				builder.build(&body)?
+			}
+			None => builder.build(&())?,
+		};
+		Event::try_from(&msg)
+	}
+}
+
+/// An [`Event`] together with the time it was received.
+///
+/// `zbus` does not record when a message was sent or received,
+/// so the timestamp is taken when a message is converted, which is usually right after it was received.
+/// Timestamps come from a monotonic clock, anchored to the system clock once per process:
+/// an event converted later never has an earlier timestamp, even if the system clock is adjusted in between.
+/// In turn, timestamps may drift from the system clock in long-running processes.
+///
+/// Dereferences to the [`Event`], so its properties can be queried directly.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TimestampedEvent {
+	event: Event,
+	timestamp: SystemTime,
+}
+
+impl TimestampedEvent {
+	/// Stamps `event` with the current time.
+	#[must_use]
+	pub fn now(event: Event) -> Self {
+		Self { event, timestamp: monotonic_now() }
+	}
+
+	/// The event.
+	#[must_use]
+	pub fn event(&self) -> &Event {
+		&self.event
+	}
+
+	/// Discards the timestamp.
+	#[must_use]
+	pub fn into_event(self) -> Event {
+		self.event
+	}
+
+	/// When the event was received. See [`TimestampedEvent`] for the exact semantics.
+	#[must_use]
+	pub fn timestamp(&self) -> SystemTime {
+		self.timestamp
+	}
+}
+
+/// The system time, advanced by a monotonic clock since its first use.
+fn monotonic_now() -> SystemTime {
+	static ANCHOR: OnceLock<(Instant, SystemTime)> = OnceLock::new();
+	let (instant, system_time) = ANCHOR.get_or_init(|| (Instant::now(), SystemTime::now()));
+	*system_time + instant.elapsed()
+}
+
+impl Deref for TimestampedEvent {
+	type Target = Event;
+
+	fn deref(&self) -> &Self::Target {
+		&self.event
+	}
+}
+
+impl From<TimestampedEvent> for Event {
+	fn from(event: TimestampedEvent) -> Self {
+		event.event
+	}
+}
+
+#[cfg(feature = "zbus")]
+impl TryFrom<&zbus::Message> for TimestampedEvent {
+	type Error = AtspiError;
+
+	fn try_from(msg: &zbus::Message) -> Result<Self, Self::Error> {
+		let timestamp = monotonic_now();
+		Ok(Self { event: Event::try_from(msg)?, timestamp })
+	}
+}
+
 /// Describes properties of a specific event _type_.
 ///
 /// - `DBus` member name
@@ -1043,6 +1358,50 @@ pub trait HasRegistryEventString {
 	const REGISTRY_EVENT_STRING: &'static str;
 }
 
+/// The match rule which registering `T` adds to the connection.
+///
+/// This allows inspecting what registration does before doing it, e.g. to show it in a settings UI.
+///
+/// ```
+/// use atspi_common::events::{match_rule_of, object::TextCaretMovedEvent};
+/// assert_eq!(
+///     match_rule_of::<TextCaretMovedEvent>(),
+///     "type='signal',interface='org.a11y.atspi.Event.Object',member='TextCaretMoved'"
+/// );
+/// ```
+#[must_use]
+pub const fn match_rule_of<T: HasMatchRule>() -> &'static str {
+	<T as HasMatchRule>::MATCH_RULE_STRING
+}
+
+/// The event string which registering `T` passes to the registry.
+///
+/// ```
+/// use atspi_common::events::{registry_string_of, object::TextCaretMovedEvent};
+/// assert_eq!(registry_string_of::<TextCaretMovedEvent>(), "Object:");
+/// ```
+#[must_use]
+pub const fn registry_string_of<T: HasRegistryEventString>() -> &'static str {
+	<T as HasRegistryEventString>::REGISTRY_EVENT_STRING
+}
+
+/// The `(registry event string, match rule)` pair of every event interface,
+/// in the order of the variants of [`Event`].
+///
+/// Registering one of these covers all events of that interface.
+pub const EVENT_INTERFACE_STRINGS: [(&str, &str); 10] = [
+	(registry_string_of::<DocumentEvents>(), match_rule_of::<DocumentEvents>()),
+	(registry_string_of::<FocusEvents>(), match_rule_of::<FocusEvents>()),
+	(registry_string_of::<KeyboardEvents>(), match_rule_of::<KeyboardEvents>()),
+	(registry_string_of::<MouseEvents>(), match_rule_of::<MouseEvents>()),
+	(registry_string_of::<ObjectEvents>(), match_rule_of::<ObjectEvents>()),
+	(registry_string_of::<TerminalEvents>(), match_rule_of::<TerminalEvents>()),
+	(registry_string_of::<WindowEvents>(), match_rule_of::<WindowEvents>()),
+	(registry_string_of::<AvailableEvent>(), match_rule_of::<AvailableEvent>()),
+	(registry_string_of::<CacheEvents>(), match_rule_of::<CacheEvents>()),
+	(registry_string_of::<EventListenerEvents>(), match_rule_of::<EventListenerEvents>()),
+];
+
 /// An way to convert a [`zbus::Message`] without checking its interface.
 #[cfg(feature = "zbus")]
 pub(crate) trait EventWrapperMessageConversion {
@@ -1077,8 +1436,13 @@ impl<T: EventWrapperMessageConversion + HasInterfaceName> TryFromMessage for T {
 
 #[cfg(test)]
 mod tests {
-	use super::{EventBodyOwned, EventBodyQT, QSPI_EVENT_SIGNATURE};
+	use super::{
+		match_rule_of, object::StateChangedEvent, registry_string_of, AvailableEvent, Event,
+		EventBodyOwned, EventBodyQT, EventTypeProperties, ObjectEvents, TimestampedEvent,
+		EVENT_INTERFACE_STRINGS, QSPI_EVENT_SIGNATURE,
+	};
 	use std::collections::HashMap;
+	use zbus_names::UniqueName;
 	use zvariant::{ObjectPath, Type};
 
 	#[test]
@@ -1096,4 +1460,162 @@ mod tests {
 		let props = HashMap::from([(name, ObjectPath::from(path).into())]);
 		assert_eq!(event_body.properties, props);
 	}
+
+	#[test]
+	fn registration_strings_of_types() {
+		assert_eq!(registry_string_of::<ObjectEvents>(), "Object:");
+		assert_eq!(
+			match_rule_of::<ObjectEvents>(),
+			"type='signal',interface='org.a11y.atspi.Event.Object'"
+		);
+	}
+
+	#[test]
+	fn event_interface_strings_are_unique() {
+		for (i, (registry, rule)) in EVENT_INTERFACE_STRINGS.iter().enumerate() {
+			for (other_registry, other_rule) in &EVENT_INTERFACE_STRINGS[i + 1..] {
+				assert_ne!(registry, other_registry);
+				assert_ne!(rule, other_rule);
+			}
+		}
+	}
+
+	#[test]
+	fn wrapped_event_interface_and_member() {
+		let event = Event::from(StateChangedEvent::default());
+		assert_eq!(event.interface(), "org.a11y.atspi.Event.Object");
+		assert_eq!(event.member(), "StateChanged");
+	}
+
+	#[cfg(feature = "zbus")]
+	#[test]
+	fn sequential_timestamps_do_not_decrease() {
+		let msg = zbus::Message::try_from(StateChangedEvent::default()).unwrap();
+		let first = TimestampedEvent::try_from(&msg).unwrap();
+		let second = TimestampedEvent::try_from(&msg).unwrap();
+		assert!(first.timestamp() <= second.timestamp());
+		assert_eq!(first.event(), second.event());
+		assert_eq!(first.member(), "StateChanged");
+	}
+
+	#[test]
+	fn event_json_round_trip() {
+		let state_changed = StateChangedEvent {
+			item: crate::ObjectRef {
+				name: ":1.2".try_into().unwrap(),
+				path: "/org/a11y/atspi/accessible/3".try_into().unwrap(),
+			},
+			state: crate::State::Focused,
+			enabled: true,
+		};
+		let event = Event::from(state_changed.clone());
+		let json = serde_json::to_string(&event).unwrap();
+		assert!(json.starts_with(r#"{"Object":{"StateChanged":"#), "{json}");
+		assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+		let json = serde_json::to_string(&state_changed).unwrap();
+		assert_eq!(serde_json::from_str::<StateChangedEvent>(&json).unwrap(), state_changed);
+
+		let stamped = TimestampedEvent::now(event);
+		let json = serde_json::to_string(&stamped).unwrap();
+		assert_eq!(serde_json::from_str::<TimestampedEvent>(&json).unwrap(), stamped);
+	}
+
+	#[test]
+	fn group_events_by_sender() {
+		let item = |name: &str| crate::ObjectRef {
+			name: name.try_into().unwrap(),
+			path: "/org/a11y/atspi/accessible/3".try_into().unwrap(),
+		};
+		let events = [
+			Event::from(StateChangedEvent { item: item(":1.2"), ..Default::default() }),
+			Event::from(AvailableEvent { item: item(":1.7"), socket: item(":1.7") }),
+			Event::from(StateChangedEvent {
+				item: item(":1.2"),
+				enabled: true,
+				..Default::default()
+			}),
+		];
+
+		let mut by_sender: HashMap<_, Vec<&Event>> = HashMap::new();
+		for event in &events {
+			by_sender.entry(event.sender()).or_default().push(event);
+		}
+		assert_eq!(by_sender.len(), 2);
+		assert_eq!(by_sender[&UniqueName::from_static_str_unchecked(":1.2")].len(), 2);
+		assert_eq!(by_sender[&UniqueName::from_static_str_unchecked(":1.7")], [&events[1]]);
+	}
+
+	#[test]
+	fn default_event_has_null_path() {
+		let event = Event::from(StateChangedEvent::default());
+		assert_eq!(event.path().as_str(), "/org/a11y/atspi/accessible/null");
+	}
+
+	#[test]
+	fn event_from_message_bytes() {
+		let body = EventBodyOwned { kind: "focused".into(), detail1: 1, ..Default::default() };
+		let msg = zbus::Message::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"StateChanged",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&body)
+		.unwrap();
+		let bytes = msg.data().bytes();
+
+		let event = Event::try_from_bytes(bytes).unwrap();
+		assert_eq!(event, Event::try_from(&msg).unwrap());
+		let Event::Object(ObjectEvents::StateChanged(state_changed)) = event else {
+			panic!("not a state change: {event:?}");
+		};
+		assert_eq!(state_changed.state, crate::State::Focused);
+		assert_eq!(state_changed.item.name.as_str(), ":1.23");
+
+		assert!(Event::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+		// On 32-bit targets, the end of such a body is past the end of the address space.
+		let mut huge_body = bytes.to_vec();
+		huge_body[4..8].copy_from_slice(&u32::MAX.to_ne_bytes());
+		assert!(Event::try_from_bytes(&huge_body).is_err());
+		assert!(Event::try_from_bytes(b"not a message").is_err());
+		assert!(Event::try_from_bytes(&[]).is_err());
+	}
+
+	#[test]
+	fn downcast_wrapped_events() {
+		let event = Event::from(StateChangedEvent::default());
+		assert_eq!(
+			event.as_object(),
+			Some(&ObjectEvents::StateChanged(StateChangedEvent::default()))
+		);
+		assert!(event.as_window().is_none());
+		assert!(event.as_cache().is_none());
+
+		let event = Event::from(crate::events::window::ActivateEvent::default());
+		assert!(event.as_window().is_some());
+		assert!(event.as_object().is_none());
+		assert!(Event::from(AvailableEvent::default()).as_focus().is_none());
+	}
+
+	#[test]
+	fn registry_string_of_event_instance() {
+		let event = Event::from(StateChangedEvent::default());
+		assert_eq!(event.registry_string(), "Object:");
+		assert_eq!(event.registry_string(), registry_string_of::<StateChangedEvent>());
+		let event = Event::from(crate::events::window::ActivateEvent::default());
+		assert_eq!(event.registry_string(), "Window:");
+	}
+
+	#[test]
+	fn match_rule_string_of_event_instance() {
+		let event = Event::from(StateChangedEvent::default());
+		assert_eq!(
+			event.match_rule_string(),
+			"type='signal',interface='org.a11y.atspi.Event.Object',member='StateChanged'"
+		);
+		assert_eq!(event.match_rule_string(), match_rule_of::<StateChangedEvent>());
+	}
 }
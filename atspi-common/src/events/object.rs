@@ -2,7 +2,8 @@ use std::hash::Hash;
 
 #[cfg(feature = "zbus")]
 use crate::events::{
-	EventWrapperMessageConversion, MessageConversion, MessageConversionExt, TryFromMessage,
+	EventBodyBorrowed, EventWrapperMessageConversion, MessageConversion, MessageConversionExt,
+	TryFromMessage,
 };
 use crate::{
 	error::AtspiError,
@@ -469,6 +470,23 @@ pub struct ChildrenChangedEvent {
 	pub child: ObjectRef,
 }
 
+impl ChildrenChangedEvent {
+	/// Whether [`ChildrenChangedEvent::child`] was added at [`ChildrenChangedEvent::index_in_parent`].
+	///
+	/// The kind of change is decoded when the event is received,
+	/// so messages with an unknown kind never become a `ChildrenChangedEvent`.
+	#[must_use]
+	pub fn is_add(&self) -> bool {
+		self.operation == crate::Operation::Insert
+	}
+
+	/// Whether [`ChildrenChangedEvent::child`] was removed from [`ChildrenChangedEvent::index_in_parent`].
+	#[must_use]
+	pub fn is_remove(&self) -> bool {
+		self.operation == crate::Operation::Delete
+	}
+}
+
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct VisibleDataChangedEvent {
 	/// The [`crate::ObjectRef`] which the event applies to.
@@ -504,6 +522,22 @@ pub struct AnnouncementEvent {
 	pub live: crate::Politeness,
 }
 
+impl AnnouncementEvent {
+	/// How urgently the announcement should be presented.
+	///
+	/// [`Politeness::Assertive`] announcements may interrupt current speech,
+	/// [`Politeness::Polite`] ones should wait until the user is idle.
+	/// The level is decoded from the event's `detail1` when the event is received,
+	/// so messages with an unknown level never become an `AnnouncementEvent`.
+	///
+	/// [`Politeness::Assertive`]: crate::Politeness::Assertive
+	/// [`Politeness::Polite`]: crate::Politeness::Polite
+	#[must_use]
+	pub fn politeness(&self) -> crate::Politeness {
+		self.live
+	}
+}
+
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct AttributesChangedEvent {
 	/// The [`crate::ObjectRef`] which the event applies to.
@@ -579,6 +613,23 @@ pub struct TextChangedEvent {
 	pub text: String,
 }
 
+impl TextChangedEvent {
+	/// Whether [`TextChangedEvent::text`] was inserted at [`TextChangedEvent::start_pos`].
+	///
+	/// The kind of change is decoded when the event is received,
+	/// so messages with an unknown kind never become a `TextChangedEvent`.
+	#[must_use]
+	pub fn is_insert(&self) -> bool {
+		self.operation == crate::Operation::Insert
+	}
+
+	/// Whether [`TextChangedEvent::text`] was deleted from [`TextChangedEvent::start_pos`].
+	#[must_use]
+	pub fn is_delete(&self) -> bool {
+		self.operation == crate::Operation::Delete
+	}
+}
+
 /// Signal that some attributes about the text (usually styling) have changed.
 /// This event does not encode _what_ has changed about the attributes, merely that they have
 /// changed.
@@ -664,14 +715,16 @@ impl MessageConversion for StateChangedEvent {
 	}
 	fn from_message_unchecked(msg: &zbus::Message) -> Result<Self, AtspiError> {
 		let item = msg.try_into()?;
-		let body = if msg.body().signature().ok_or(AtspiError::MissingSignature)?
+		let body = msg.body();
+		if body.signature().ok_or(AtspiError::MissingSignature)?
 			== crate::events::QSPI_EVENT_SIGNATURE
 		{
-			msg.body().deserialize::<crate::events::EventBodyQT>()?.into()
-		} else {
-			msg.body().deserialize()?
-		};
-		Self::from_message_unchecked_parts(item, body)
+			let body = body.deserialize::<crate::events::EventBodyQT>()?.into();
+			return Self::from_message_unchecked_parts(item, body);
+		}
+		// This is one of the most frequent events; borrowing the body avoids copying its `kind`.
+		let body: EventBodyBorrowed = body.deserialize()?;
+		Ok(Self { item, state: body.kind.into(), enabled: body.detail1 > 0 })
 	}
 	fn body(&self) -> Self::Body {
 		let copy = self.clone();
@@ -943,14 +996,16 @@ impl MessageConversion for TextCaretMovedEvent {
 	}
 	fn from_message_unchecked(msg: &zbus::Message) -> Result<Self, AtspiError> {
 		let item = msg.try_into()?;
-		let body = if msg.body().signature().ok_or(AtspiError::MissingSignature)?
+		let body = msg.body();
+		if body.signature().ok_or(AtspiError::MissingSignature)?
 			== crate::events::QSPI_EVENT_SIGNATURE
 		{
-			msg.body().deserialize::<crate::events::EventBodyQT>()?.into()
-		} else {
-			msg.body().deserialize()?
-		};
-		Self::from_message_unchecked_parts(item, body)
+			let body = body.deserialize::<crate::events::EventBodyQT>()?.into();
+			return Self::from_message_unchecked_parts(item, body);
+		}
+		// Sent on every caret movement; borrowing the body avoids copying its `kind`.
+		let body: EventBodyBorrowed = body.deserialize()?;
+		Ok(Self { item, position: body.detail1 })
 	}
 	fn body(&self) -> Self::Body {
 		let copy = self.clone();
@@ -1512,3 +1567,135 @@ impl From<TextCaretMovedEvent> for EventBodyOwned {
 impl HasRegistryEventString for ObjectEvents {
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 }
+
+#[cfg(all(test, feature = "zbus"))]
+mod tests {
+	use super::{AnnouncementEvent, ChildrenChangedEvent, StateChangedEvent, TextChangedEvent};
+	use crate::{events::EventBodyOwned, Politeness, State};
+	use zvariant::OwnedValue;
+
+	#[test]
+	fn state_changed_event_decodes_state() {
+		let body = EventBodyOwned { kind: "focused".into(), detail1: 1, ..Default::default() };
+		let msg = zbus::Message::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"StateChanged",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&body)
+		.unwrap();
+		let event = StateChangedEvent::try_from(&msg).unwrap();
+		assert_eq!(event.state, State::Focused);
+		assert!(event.enabled);
+	}
+
+	fn announcement(politeness: i32) -> zbus::Message {
+		let body = EventBodyOwned {
+			detail1: politeness,
+			any_data: OwnedValue::try_from(zvariant::Value::from("Download complete")).unwrap(),
+			..Default::default()
+		};
+		zbus::Message::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"Announcement",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&body)
+		.unwrap()
+	}
+
+	#[test]
+	fn announcement_event_decodes_politeness() {
+		let event = AnnouncementEvent::try_from(&announcement(2)).unwrap();
+		assert_eq!(event.politeness(), Politeness::Assertive);
+		assert_eq!(event.text, "Download complete");
+		assert!(AnnouncementEvent::try_from(&announcement(3)).is_err());
+	}
+
+	fn text_changed(kind: &str) -> zbus::Message {
+		let body = EventBodyOwned {
+			kind: kind.into(),
+			detail1: 4,
+			detail2: 3,
+			any_data: OwnedValue::try_from(zvariant::Value::from("ing")).unwrap(),
+			..Default::default()
+		};
+		zbus::Message::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"TextChanged",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&body)
+		.unwrap()
+	}
+
+	#[test]
+	fn text_changed_event_insert() {
+		let event = TextChangedEvent::try_from(&text_changed("insert")).unwrap();
+		assert!(event.is_insert());
+		assert!(!event.is_delete());
+		assert_eq!((event.start_pos, event.length, event.text.as_str()), (4, 3, "ing"));
+	}
+
+	#[test]
+	fn text_changed_event_delete() {
+		let event = TextChangedEvent::try_from(&text_changed("delete/system")).unwrap();
+		assert!(event.is_delete());
+		assert!(!event.is_insert());
+	}
+
+	#[test]
+	fn text_changed_event_with_unknown_kind_is_rejected() {
+		assert!(TextChangedEvent::try_from(&text_changed("replace")).is_err());
+	}
+
+	fn children_changed(kind: &str) -> zbus::Message {
+		let child = crate::ObjectRef {
+			name: ":1.23".try_into().unwrap(),
+			path: "/org/a11y/atspi/accessible/2".try_into().unwrap(),
+		};
+		let body = EventBodyOwned {
+			kind: kind.into(),
+			detail1: 1,
+			any_data: OwnedValue::try_from(zvariant::Value::from(child)).unwrap(),
+			..Default::default()
+		};
+		zbus::Message::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"ChildrenChanged",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&body)
+		.unwrap()
+	}
+
+	#[test]
+	fn children_changed_event_add_and_remove() {
+		let added = ChildrenChangedEvent::try_from(&children_changed("add")).unwrap();
+		assert!(added.is_add());
+		assert!(!added.is_remove());
+		assert_eq!(added.index_in_parent, 1);
+		assert_eq!(added.child.path.as_str(), "/org/a11y/atspi/accessible/2");
+
+		let removed = ChildrenChangedEvent::try_from(&children_changed("remove/system")).unwrap();
+		assert!(removed.is_remove());
+		assert!(!removed.is_add());
+	}
+
+	#[test]
+	fn children_changed_event_with_unknown_kind_is_rejected() {
+		assert!(ChildrenChangedEvent::try_from(&children_changed("moved")).is_err());
+	}
+}
@@ -1,12 +1,17 @@
-#[cfg(feature = "zbus")]
-use crate::events::{
-	EventWrapperMessageConversion, MessageConversion, MessageConversionExt, TryFromMessage,
-};
 use crate::{
 	error::AtspiError,
-	events::{BusProperties, HasInterfaceName, HasMatchRule, HasRegistryEventString},
+	events::{
+		BusProperties, EventBodyOwned, HasInterfaceName, HasMatchRule, HasRegistryEventString,
+	},
 	Event, EventProperties, EventTypeProperties,
 };
+#[cfg(feature = "zbus")]
+use crate::{
+	events::{
+		EventWrapperMessageConversion, MessageConversion, MessageConversionExt, TryFromMessage,
+	},
+	ObjectRef,
+};
 use zbus_names::UniqueName;
 use zvariant::ObjectPath;
 
@@ -108,6 +113,8 @@ pub struct LineChangedEvent {
 pub struct ColumnCountChangedEvent {
 	/// The [`crate::ObjectRef`] which the event applies to.
 	pub item: crate::events::ObjectRef,
+	/// The new number of characters which fit on one *visual* line.
+	pub count: i32,
 }
 
 /// The height of a terminal emulator has changed sufficiently such that the number of lines
@@ -116,6 +123,8 @@ pub struct ColumnCountChangedEvent {
 pub struct LineCountChangedEvent {
 	/// The [`crate::ObjectRef`] which the event applies to.
 	pub item: crate::events::ObjectRef,
+	/// The new number of lines which fit within the terminal.
+	pub count: i32,
 }
 
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
@@ -148,6 +157,30 @@ impl BusProperties for ColumnCountChangedEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 }
 
+#[cfg(feature = "zbus")]
+impl MessageConversion for ColumnCountChangedEvent {
+	type Body = EventBodyOwned;
+
+	fn from_message_unchecked_parts(item: ObjectRef, body: Self::Body) -> Result<Self, AtspiError> {
+		Ok(Self { item, count: body.detail1 })
+	}
+	fn from_message_unchecked(msg: &zbus::Message) -> Result<Self, AtspiError> {
+		let item = msg.try_into()?;
+		let body = if msg.body().signature().ok_or(AtspiError::MissingSignature)?
+			== crate::events::QSPI_EVENT_SIGNATURE
+		{
+			msg.body().deserialize::<crate::events::EventBodyQT>()?.into()
+		} else {
+			msg.body().deserialize()?
+		};
+		Self::from_message_unchecked_parts(item, body)
+	}
+	fn body(&self) -> Self::Body {
+		let copy = self.clone();
+		copy.into()
+	}
+}
+
 impl BusProperties for LineCountChangedEvent {
 	const DBUS_MEMBER: &'static str = "LinecountChanged";
 	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
@@ -156,6 +189,30 @@ impl BusProperties for LineCountChangedEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 }
 
+#[cfg(feature = "zbus")]
+impl MessageConversion for LineCountChangedEvent {
+	type Body = EventBodyOwned;
+
+	fn from_message_unchecked_parts(item: ObjectRef, body: Self::Body) -> Result<Self, AtspiError> {
+		Ok(Self { item, count: body.detail1 })
+	}
+	fn from_message_unchecked(msg: &zbus::Message) -> Result<Self, AtspiError> {
+		let item = msg.try_into()?;
+		let body = if msg.body().signature().ok_or(AtspiError::MissingSignature)?
+			== crate::events::QSPI_EVENT_SIGNATURE
+		{
+			msg.body().deserialize::<crate::events::EventBodyQT>()?.into()
+		} else {
+			msg.body().deserialize()?
+		};
+		Self::from_message_unchecked_parts(item, body)
+	}
+	fn body(&self) -> Self::Body {
+		let copy = self.clone();
+		copy.into()
+	}
+}
+
 impl BusProperties for ApplicationChangedEvent {
 	const DBUS_MEMBER: &'static str = "ApplicationChanged";
 	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
@@ -244,7 +301,17 @@ event_test_cases!(ColumnCountChangedEvent);
 impl_to_dbus_message!(ColumnCountChangedEvent);
 impl_from_dbus_message!(ColumnCountChangedEvent);
 impl_event_properties!(ColumnCountChangedEvent);
-impl_from_object_ref!(ColumnCountChangedEvent);
+impl From<ColumnCountChangedEvent> for EventBodyOwned {
+	fn from(event: ColumnCountChangedEvent) -> Self {
+		EventBodyOwned {
+			properties: std::collections::HashMap::new(),
+			kind: String::default(),
+			detail1: event.count,
+			detail2: i32::default(),
+			any_data: u8::default().into(),
+		}
+	}
+}
 
 impl_from_user_facing_event_for_interface_event_enum!(
 	LineCountChangedEvent,
@@ -261,7 +328,17 @@ event_test_cases!(LineCountChangedEvent);
 impl_to_dbus_message!(LineCountChangedEvent);
 impl_from_dbus_message!(LineCountChangedEvent);
 impl_event_properties!(LineCountChangedEvent);
-impl_from_object_ref!(LineCountChangedEvent);
+impl From<LineCountChangedEvent> for EventBodyOwned {
+	fn from(event: LineCountChangedEvent) -> Self {
+		EventBodyOwned {
+			properties: std::collections::HashMap::new(),
+			kind: String::default(),
+			detail1: event.count,
+			detail2: i32::default(),
+			any_data: u8::default().into(),
+		}
+	}
+}
 
 impl_from_user_facing_event_for_interface_event_enum!(
 	ApplicationChangedEvent,
@@ -300,3 +377,38 @@ impl_from_object_ref!(CharWidthChangedEvent);
 impl HasRegistryEventString for TerminalEvents {
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 }
+
+#[cfg(all(test, feature = "zbus"))]
+mod tests {
+	use super::{ColumnCountChangedEvent, LineCountChangedEvent, TerminalEvents};
+	use crate::{events::ObjectRef, Event};
+
+	fn terminal() -> ObjectRef {
+		ObjectRef {
+			name: ":1.23".try_into().unwrap(),
+			path: "/org/a11y/atspi/accessible/terminal".try_into().unwrap(),
+		}
+	}
+
+	#[test]
+	fn line_count_changed_round_trips_count() {
+		let event = LineCountChangedEvent { item: terminal(), count: 42 };
+		let msg = zbus::Message::try_from(event.clone()).unwrap();
+		assert_eq!(msg.header().member().unwrap().as_str(), "LinecountChanged");
+
+		let decoded = LineCountChangedEvent::try_from(&msg).unwrap();
+		assert_eq!(decoded.count, 42);
+		assert_eq!(decoded, event);
+		assert_eq!(
+			Event::try_from(&msg).unwrap(),
+			Event::Terminal(TerminalEvents::LineCountChanged(event))
+		);
+	}
+
+	#[test]
+	fn column_count_changed_round_trips_count() {
+		let event = ColumnCountChangedEvent { item: terminal(), count: 80 };
+		let msg = zbus::Message::try_from(event.clone()).unwrap();
+		assert_eq!(ColumnCountChangedEvent::try_from(&msg).unwrap(), event);
+	}
+}
@@ -79,6 +79,34 @@ pub enum Interface {
 	Value,
 }
 
+impl fmt::Display for Interface {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let interface_str = match self {
+			Interface::Accessible => "Accessible",
+			Interface::Action => "Action",
+			Interface::Application => "Application",
+			Interface::Cache => "Cache",
+			Interface::Collection => "Collection",
+			Interface::Component => "Component",
+			Interface::Document => "Document",
+			Interface::DeviceEventController => "DeviceEventController",
+			Interface::DeviceEventListener => "DeviceEventListener",
+			Interface::EditableText => "EditableText",
+			Interface::Hyperlink => "Hyperlink",
+			Interface::Hypertext => "Hypertext",
+			Interface::Image => "Image",
+			Interface::Registry => "Registry",
+			Interface::Selection => "Selection",
+			Interface::Socket => "Socket",
+			Interface::Table => "Table",
+			Interface::TableCell => "TableCell",
+			Interface::Text => "Text",
+			Interface::Value => "Value",
+		};
+		f.write_str(interface_str)
+	}
+}
+
 /// A collection type which encodes the AT-SPI interfaces an accessible object has implemented.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct InterfaceSet(BitFlags<Interface>);
@@ -107,6 +135,12 @@ impl InterfaceSet {
 		self.0.contains(other)
 	}
 
+	/// Whether every interface in `other` is also in `self`, e.g. to check an object supports all interfaces a task needs.
+	#[must_use]
+	pub fn is_superset_of(&self, other: InterfaceSet) -> bool {
+		self.0.contains(other.0)
+	}
+
 	pub fn insert<B: Into<BitFlags<Interface>>>(&mut self, other: B) {
 		self.0.insert(other);
 	}
@@ -141,6 +175,21 @@ impl Default for InterfaceSet {
 	}
 }
 
+/// Lists the interfaces in the set, in enum order, separated by commas, e.g. `Accessible, Component, Text`.
+///
+/// An empty set displays as an empty string.
+impl fmt::Display for InterfaceSet {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, interface) in self.iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "{interface}")?;
+		}
+		Ok(())
+	}
+}
+
 impl<'de> de::Deserialize<'de> for InterfaceSet {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -228,6 +277,15 @@ impl std::ops::BitOr for InterfaceSet {
 	}
 }
 
+/// The interfaces of `self` which are not in `other`.
+impl std::ops::Sub for InterfaceSet {
+	type Output = InterfaceSet;
+
+	fn sub(self, other: Self) -> Self::Output {
+		InterfaceSet(self.0 & !other.0)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Interface, InterfaceSet};
@@ -307,4 +365,41 @@ mod tests {
 		assert_eq!(iter.next(), Some(Interface::Component));
 		assert_eq!(iter.next(), Some(Interface::Text));
 	}
+
+	#[test]
+	fn display_interface_set() {
+		let set = InterfaceSet::new(Interface::Text | Interface::Accessible | Interface::Component);
+		assert_eq!(set.to_string(), "Accessible, Component, Text");
+		assert_eq!(InterfaceSet::new(Interface::TableCell).to_string(), "TableCell");
+		assert_eq!(InterfaceSet::empty().to_string(), "");
+	}
+
+	#[test]
+	fn display_interface_matches_dbus_name() {
+		for interface in InterfaceSet::all() {
+			let dbus_name = serde_plain::to_string(&interface).unwrap();
+			assert_eq!(dbus_name, format!("org.a11y.atspi.{interface}"));
+		}
+	}
+
+	#[test]
+	fn superset_of_interface_set() {
+		let object =
+			InterfaceSet::new(Interface::Accessible | Interface::Component | Interface::Text);
+		let needed = InterfaceSet::new(Interface::Accessible | Interface::Text);
+		assert!(object.is_superset_of(needed));
+		assert!(!needed.is_superset_of(object));
+		assert!(object.is_superset_of(object));
+		assert!(needed.is_superset_of(InterfaceSet::empty()));
+	}
+
+	#[test]
+	fn interface_set_operators() {
+		let object =
+			InterfaceSet::new(Interface::Accessible | Interface::Component | Interface::Text);
+		let needed = InterfaceSet::new(Interface::Text | Interface::EditableText);
+		assert_eq!(object & needed, InterfaceSet::new(Interface::Text));
+		assert_eq!(needed - object, InterfaceSet::new(Interface::EditableText));
+		assert!((object | needed).is_superset_of(needed));
+	}
 }
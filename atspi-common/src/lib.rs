@@ -32,6 +32,8 @@ pub mod error;
 pub use error::AtspiError;
 pub mod events;
 pub use events::{BusProperties, Event, EventProperties, EventTypeProperties};
+pub mod tree;
+pub use tree::Tree;
 mod role;
 pub use role::Role;
 mod relation_type;
@@ -62,6 +64,51 @@ pub struct TextSelection {
 	start_is_active: bool,
 }
 
+impl TextSelection {
+	/// A selection from `start_idx` in `start_obj` to `end_idx` in `end_obj`,
+	/// e.g. to pass to `atspi_proxies::document::DocumentProxy::set_text_selections`.
+	#[must_use]
+	pub fn new(
+		start_obj: ObjectRef,
+		start_idx: i32,
+		end_obj: ObjectRef,
+		end_idx: i32,
+		start_is_active: bool,
+	) -> Self {
+		Self { start_obj, start_idx, end_obj, end_idx, start_is_active }
+	}
+
+	/// The object in which the selection starts.
+	#[must_use]
+	pub fn start_obj(&self) -> &ObjectRef {
+		&self.start_obj
+	}
+
+	/// The text offset within [`TextSelection::start_obj`] at which the selection starts.
+	#[must_use]
+	pub fn start_idx(&self) -> i32 {
+		self.start_idx
+	}
+
+	/// The object in which the selection ends.
+	#[must_use]
+	pub fn end_obj(&self) -> &ObjectRef {
+		&self.end_obj
+	}
+
+	/// The text offset within [`TextSelection::end_obj`] at which the selection ends.
+	#[must_use]
+	pub fn end_idx(&self) -> i32 {
+		self.end_idx
+	}
+
+	/// Whether [`TextSelection::start_obj`] is active.
+	#[must_use]
+	pub fn start_is_active(&self) -> bool {
+		self.start_is_active
+	}
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[repr(u32)]
 /// The coordinate type encodes the frame of reference.
@@ -74,6 +121,60 @@ pub enum CoordType {
 	Parent,
 }
 
+impl TryFrom<u32> for CoordType {
+	type Error = AtspiError;
+
+	fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+		match value {
+			0 => Ok(CoordType::Screen),
+			1 => Ok(CoordType::Window),
+			2 => Ok(CoordType::Parent),
+			_ => Err(AtspiError::Conversion("Unknown CoordType variant")),
+		}
+	}
+}
+
+/// A rectangle on screen, as reported by the `GetExtents` family of methods.
+///
+/// Whether `x` and `y` are relative to the screen, window or parent depends on the [`CoordType`]
+/// used to query or create it.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct Rect {
+	/// Horizontal position of the top left corner.
+	pub x: i32,
+	/// Vertical position of the top left corner.
+	pub y: i32,
+	/// Width of the rectangle.
+	pub width: i32,
+	/// Height of the rectangle.
+	pub height: i32,
+}
+
+impl Rect {
+	/// Creates a new `Rect` from its top left corner and size.
+	#[must_use]
+	pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+		Self { x, y, width, height }
+	}
+
+	/// Whether the two rectangles share any area.
+	///
+	/// Rectangles which merely touch at an edge do not intersect.
+	#[must_use]
+	pub fn intersects(&self, other: &Rect) -> bool {
+		self.x < other.x + other.width
+			&& other.x < self.x + self.width
+			&& self.y < other.y + other.height
+			&& other.y < self.y + self.height
+	}
+}
+
+impl From<(i32, i32, i32, i32)> for Rect {
+	fn from((x, y, width, height): (i32, i32, i32, i32)) -> Self {
+		Self { x, y, width, height }
+	}
+}
+
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[repr(u32)]
 /// Enumeration used by `TextProxy` to indicate how to treat characters intersecting bounding boxes.
@@ -88,6 +189,20 @@ pub enum ClipType {
 	Both,
 }
 
+impl TryFrom<u32> for ClipType {
+	type Error = AtspiError;
+
+	fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+		match value {
+			0 => Ok(ClipType::Neither),
+			1 => Ok(ClipType::Min),
+			2 => Ok(ClipType::Max),
+			3 => Ok(ClipType::Both),
+			_ => Err(AtspiError::Conversion("Unknown ClipType variant")),
+		}
+	}
+}
+
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[repr(u32)]
 /// Level of granularity to get text of, in relation to a cursor position.
@@ -143,6 +258,24 @@ pub enum Layer {
 	Window,
 }
 
+impl TryFrom<u32> for Layer {
+	type Error = AtspiError;
+
+	fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+		match value {
+			0 => Ok(Layer::Invalid),
+			1 => Ok(Layer::Background),
+			2 => Ok(Layer::Canvas),
+			3 => Ok(Layer::Widget),
+			4 => Ok(Layer::Mdi),
+			5 => Ok(Layer::Popup),
+			6 => Ok(Layer::Overlay),
+			7 => Ok(Layer::Window),
+			_ => Err(AtspiError::UnknownLayer(value)),
+		}
+	}
+}
+
 /// Enumeration used by interface the [`crate::interface::Interface::Accessible`] to specify where an object should be placed on the screen when using `scroll_to`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
 pub enum ScrollType {
@@ -162,6 +295,23 @@ pub enum ScrollType {
 	Anywhere,
 }
 
+impl TryFrom<u32> for ScrollType {
+	type Error = AtspiError;
+
+	fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+		match value {
+			0 => Ok(ScrollType::TopLeft),
+			1 => Ok(ScrollType::BottomRight),
+			2 => Ok(ScrollType::TopEdge),
+			3 => Ok(ScrollType::BottomEdge),
+			4 => Ok(ScrollType::LeftEdge),
+			5 => Ok(ScrollType::RightEdge),
+			6 => Ok(ScrollType::Anywhere),
+			_ => Err(AtspiError::Conversion("Unknown ScrollType variant")),
+		}
+	}
+}
+
 /// Enumeration used to indicate a type of live region and how assertive it
 /// should be in terms of speaking notifications. Currently, this is only used
 /// for `Announcement` events, but it may be used for additional purposes
@@ -208,6 +358,48 @@ mod tests {
 		assert!(Politeness::try_from(-1).is_err());
 	}
 
+	#[test]
+	fn convert_u32_to_coord_type() {
+		assert_eq!(CoordType::Screen, CoordType::try_from(0).unwrap());
+		assert_eq!(CoordType::Window, CoordType::try_from(1).unwrap());
+		assert_eq!(CoordType::Parent, CoordType::try_from(2).unwrap());
+		assert!(matches!(CoordType::try_from(3), Err(AtspiError::Conversion(_))));
+	}
+
+	#[test]
+	fn convert_u32_to_layer() {
+		assert_eq!(Layer::Invalid, Layer::try_from(0).unwrap());
+		assert_eq!(Layer::Widget, Layer::try_from(Layer::Widget as u32).unwrap());
+		assert_eq!(Layer::Window, Layer::try_from(7).unwrap());
+		assert!(matches!(Layer::try_from(42), Err(AtspiError::UnknownLayer(42))));
+	}
+
+	#[test]
+	fn convert_u32_to_clip_type() {
+		assert_eq!(ClipType::Neither, ClipType::try_from(0).unwrap());
+		assert_eq!(ClipType::Min, ClipType::try_from(1).unwrap());
+		assert_eq!(ClipType::Max, ClipType::try_from(2).unwrap());
+		assert_eq!(ClipType::Both, ClipType::try_from(3).unwrap());
+		assert!(matches!(ClipType::try_from(4), Err(AtspiError::Conversion(_))));
+	}
+
+	#[test]
+	fn convert_u32_to_scroll_type() {
+		let variants = [
+			ScrollType::TopLeft,
+			ScrollType::BottomRight,
+			ScrollType::TopEdge,
+			ScrollType::BottomEdge,
+			ScrollType::LeftEdge,
+			ScrollType::RightEdge,
+			ScrollType::Anywhere,
+		];
+		for (value, variant) in (0..).zip(variants) {
+			assert_eq!(variant, ScrollType::try_from(value).unwrap());
+		}
+		assert!(matches!(ScrollType::try_from(7), Err(AtspiError::Conversion(_))));
+	}
+
 	#[test]
 	fn validate_live_signature() {
 		let signature = signal_body_type_signature!("Announcement");
@@ -245,6 +437,21 @@ mod tests {
 		assert_eq!(CoordType::signature(), signature);
 	}
 
+	#[test]
+	fn validate_rect_signature() {
+		let signature = method_return_signature!("GetExtents");
+		assert_eq!(Rect::signature(), signature);
+	}
+
+	#[test]
+	fn rect_intersection() {
+		let rect = Rect::new(0, 0, 100, 20);
+		assert!(rect.intersects(&Rect::new(50, 10, 100, 100)));
+		assert!(!rect.intersects(&Rect::new(100, 0, 10, 10)));
+		assert!(!rect.intersects(&Rect::new(0, 20, 100, 20)));
+		assert_eq!(Rect::from((1, 2, 3, 4)), Rect::new(1, 2, 3, 4));
+	}
+
 	#[test]
 	fn validate_match_type_signature() {
 		let rule_signature = method_args_signature!(member: "GetMatchesTo", interface: "org.a11y.atspi.Collection", argument: "rule");
@@ -259,4 +466,19 @@ mod tests {
 		// this signature is written: `a(...)`, where `(...)` is the signature we want to compare against
 		assert_eq!(TextSelection::signature(), selection_signature);
 	}
+
+	#[test]
+	fn text_selection_new_and_accessors() {
+		let paragraph = |n: u32| ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/{n}").try_into().unwrap(),
+		};
+		let selection = TextSelection::new(paragraph(1), 5, paragraph(2), 3, true);
+		assert_eq!(selection.start_obj(), &paragraph(1));
+		assert_eq!(selection.start_idx(), 5);
+		assert_eq!(selection.end_obj(), &paragraph(2));
+		assert_eq!(selection.end_idx(), 3);
+		assert!(selection.start_is_active());
+		assert_eq!(TextSelection::signature(), "((so)i(so)ib)");
+	}
 }
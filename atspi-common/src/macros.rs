@@ -280,29 +280,28 @@ macro_rules! impl_from_dbus_message {
 		impl TryFrom<&zbus::Message> for $type {
 			type Error = AtspiError;
 			fn try_from(msg: &zbus::Message) -> Result<Self, Self::Error> {
-        use zvariant::Type;
+				use zvariant::Type;
 
-        Self::validate_interface(msg)?;
-        Self::validate_member(msg)?;
+				Self::validate_interface(msg)?;
+				Self::validate_member(msg)?;
 
-        let body = msg.body();
-        let body_signature = body.signature().ok_or(AtspiError::MissingSignature)?;
-        let deser_body: <Self as MessageConversion>::Body = if body_signature == crate::events::QSPI_EVENT_SIGNATURE {
-            let qtbody: crate::events::EventBodyQT = body.deserialize_unchecked()?;
-            qtbody.into()
-        } else if body_signature == crate::events::ATSPI_EVENT_SIGNATURE {
-            body.deserialize_unchecked()?
-        } else {
-          return Err(AtspiError::SignatureMatch(format!(
-            "The message signature {} does not match the signal's body signature: {}",
-            body_signature,
-            <Self as MessageConversion>::Body::signature().as_str(),
-          )));
-        };
-        let item = msg.try_into()?;
-        Self::from_message_unchecked_parts(item, deser_body)
-      }
-    }
+				let body = msg.body();
+				let body_signature = body.signature().ok_or(AtspiError::MissingSignature)?;
+				if body_signature == crate::events::QSPI_EVENT_SIGNATURE {
+					let qtbody: crate::events::EventBodyQT = body.deserialize_unchecked()?;
+					let item = msg.try_into()?;
+					return Self::from_message_unchecked_parts(item, qtbody.into());
+				} else if body_signature != crate::events::ATSPI_EVENT_SIGNATURE {
+					return Err(AtspiError::SignatureMatch(format!(
+						"The message signature {} does not match the signal's body signature: {}",
+						body_signature,
+						<Self as MessageConversion>::Body::signature().as_str(),
+					)));
+				}
+				// Lets event types which only read part of the body borrow it, see `EventBodyBorrowed`.
+				Self::from_message_unchecked(msg)
+			}
+		}
 	};
 	($type:ty, Explicit) => {
 		#[cfg(feature = "zbus")]
@@ -1,9 +1,9 @@
-use std::{borrow::Borrow, collections::HashMap, marker::PhantomData};
+use std::{borrow::Borrow, collections::HashMap, fmt, marker::PhantomData};
 
 use serde::{Deserialize, Serialize};
 use zvariant::{Signature, Type};
 
-use crate::{Interface, InterfaceSet, Role, State, StateSet};
+use crate::{AtspiError, Interface, InterfaceSet, Role, State, StateSet};
 
 /// Defines how an object-tree is to be traversed.
 /// Used in `CollectionProxy`.
@@ -21,6 +21,16 @@ pub enum TreeTraversalType {
 	Inorder,
 }
 
+impl fmt::Display for TreeTraversalType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			TreeTraversalType::RestrictChildren => "restrict children",
+			TreeTraversalType::RestrictSibling => "restrict sibling",
+			TreeTraversalType::Inorder => "inorder",
+		})
+	}
+}
+
 /// Definition of match rules for accessible objects.
 /// Rule(s) against which we can match an  object or a collection thereof.
 ///
@@ -68,6 +78,47 @@ impl ObjectMatchRule {
 	pub fn builder() -> ObjectMatchRuleBuilder {
 		ObjectMatchRuleBuilder::default()
 	}
+
+	/// Checks the rule for mistakes which would otherwise silently yield no, or all, matches.
+	///
+	/// This is [`ObjectMatchRule::validate_with`], rejecting rules which match every object.
+	///
+	/// # Errors
+	///
+	/// See [`ObjectMatchRule::validate_with`].
+	pub fn validate(&self) -> Result<(), AtspiError> {
+		self.validate_with(false)
+	}
+
+	/// Checks the rule for mistakes which would otherwise silently yield no, or all, matches.
+	///
+	/// A rule with all criteria empty, each with [`MatchType::All`], matches every object.
+	/// Pass `allow_match_all` if that is intended, e.g. to collect the whole tree.
+	///
+	/// # Errors
+	///
+	/// - If any criterion uses [`MatchType::Invalid`].
+	/// - If the rule matches every object and `allow_match_all` is `false`.
+	pub fn validate_with(&self, allow_match_all: bool) -> Result<(), AtspiError> {
+		let criteria = [
+			("states", self.states_mt, self.states.is_empty()),
+			("attributes", self.attr_mt, self.attr.is_empty()),
+			("roles", self.roles_mt, self.roles.is_empty()),
+			("interfaces", self.ifaces_mt, self.ifaces.bits() == 0),
+		];
+		if let Some((name, _, _)) = criteria.iter().find(|(_, mt, _)| *mt == MatchType::Invalid) {
+			return Err(AtspiError::Owned(format!(
+				"match type of {name} is invalid, which matches nothing"
+			)));
+		}
+		let matches_all = criteria.iter().all(|&(_, mt, empty)| empty && mt == MatchType::All);
+		if matches_all && !self.invert && !allow_match_all {
+			return Err(AtspiError::Owned(
+				"all criteria are empty, which matches every object".to_owned(),
+			));
+		}
+		Ok(())
+	}
 }
 
 /// The 'builder' type for `MatchRule`.  
@@ -177,7 +228,21 @@ pub enum MatchType {
 	Empty,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+impl MatchType {
+	/// A short description of how the criterion is matched, for logging.
+	#[must_use]
+	pub fn describe(&self) -> &'static str {
+		match self {
+			Self::Invalid => "invalid, matches nothing",
+			Self::All => "all must match",
+			Self::Any => "any must match",
+			Self::NA => "none may match",
+			Self::Empty => "all must match, or both must be empty",
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type, Default)]
 #[repr(u32)]
 /// Enumeration used by interface `CollectionProxy` to specify the way [`ObjectRef`]
 /// objects should be sorted.
@@ -196,6 +261,7 @@ pub enum SortOrder {
 	Invalid,
 
 	/// Canonical sort order
+	#[default]
 	Canonical,
 
 	/// Flow sort order
@@ -222,6 +288,20 @@ pub enum SortOrder {
 	ReverseTab,
 }
 
+impl fmt::Display for SortOrder {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			SortOrder::Invalid => "invalid",
+			SortOrder::Canonical => "canonical",
+			SortOrder::Flow => "flow",
+			SortOrder::Tab => "tab",
+			SortOrder::ReverseCanonical => "reverse canonical",
+			SortOrder::ReverseFlow => "reverse flow",
+			SortOrder::ReverseTab => "reverse tab",
+		})
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -287,4 +367,79 @@ mod tests {
 		assert_eq!(rule.ifaces, InterfaceSet::new(Interface::Action));
 		assert!(rule.invert);
 	}
+
+	#[test]
+	fn built_rule_encodes_in_collection_field_order() {
+		use zvariant::{serialized::Context, to_bytes, LE};
+
+		type RawRule =
+			(Vec<i32>, i32, HashMap<String, String>, i32, Vec<i32>, i32, Vec<String>, i32, bool);
+
+		let rule = ObjectMatchRule::builder()
+			.states([State::Active], MatchType::Any)
+			.roles(&[Role::Alert], MatchType::NA)
+			.interfaces([Interface::Action], MatchType::Empty)
+			.build();
+		let encoded = to_bytes(Context::new_dbus(LE, 0), &rule).unwrap();
+		let (raw, _) = encoded.deserialize::<RawRule>().unwrap();
+		assert_eq!(<RawRule as Type>::signature(), ObjectMatchRule::signature());
+
+		let (states, states_mt, attr, attr_mt, roles, roles_mt, ifaces, ifaces_mt, invert) = raw;
+		assert_eq!(states, [1 << State::Active.index(), 0]);
+		assert_eq!(states_mt, MatchType::Any as i32);
+		assert!(attr.is_empty());
+		assert_eq!(attr_mt, MatchType::All as i32);
+		assert_eq!(roles, [Role::Alert as i32]);
+		assert_eq!(roles_mt, MatchType::NA as i32);
+		assert_eq!(ifaces, ["org.a11y.atspi.Action"]);
+		assert_eq!(ifaces_mt, MatchType::Empty as i32);
+		assert!(!invert);
+	}
+
+	#[test]
+	fn valid_rule_passes_validation() {
+		let rule = ObjectMatchRule::builder()
+			.roles(&[Role::Button], MatchType::Any)
+			.states([State::Focusable], MatchType::All)
+			.build();
+		assert!(rule.validate().is_ok());
+	}
+
+	#[test]
+	fn invalid_match_type_fails_validation() {
+		let rule = ObjectMatchRule::builder()
+			.roles(&[Role::Button], MatchType::Any)
+			.interfaces([Interface::Action], MatchType::Invalid)
+			.build();
+		assert!(rule.validate().is_err());
+		assert!(rule.validate_with(true).is_err());
+	}
+
+	#[test]
+	fn match_all_rule_is_configurable() {
+		let rule = ObjectMatchRule::builder().build();
+		assert!(rule.validate().is_err());
+		assert!(rule.validate_with(true).is_ok());
+		assert!(ObjectMatchRule::builder().invert(true).build().validate().is_ok());
+	}
+
+	#[test]
+	fn match_types_describe_themselves() {
+		assert_eq!(MatchType::NA.describe(), "none may match");
+		assert_eq!(MatchType::default().describe(), "all must match");
+	}
+
+	#[test]
+	fn collection_enum_defaults() {
+		assert_eq!(SortOrder::default(), SortOrder::Canonical);
+		assert_eq!(TreeTraversalType::default(), TreeTraversalType::Inorder);
+	}
+
+	#[test]
+	fn collection_enum_display() {
+		assert_eq!(SortOrder::Canonical.to_string(), "canonical");
+		assert_eq!(SortOrder::ReverseCanonical.to_string(), "reverse canonical");
+		assert_eq!(TreeTraversalType::RestrictChildren.to_string(), "restrict children");
+		assert_eq!(TreeTraversalType::Inorder.to_string(), "inorder");
+	}
 }
@@ -20,15 +20,83 @@ pub struct ObjectRef {
 	pub path: OwnedObjectPath,
 }
 
-impl Default for ObjectRef {
-	fn default() -> Self {
+impl ObjectRef {
+	/// The sender of [`ObjectRef::null`].
+	pub const NULL_NAME: &'static str = ":0.0";
+
+	/// The path of [`ObjectRef::null`], which applications use to report "no object".
+	pub const NULL_PATH: &'static str = "/org/a11y/atspi/accessible/null";
+
+	/// The path `at-spi2-core` uses for the null object, e.g. as the parent of an application.
+	const ATSPI_NULL_PATH: &'static str = "/org/a11y/atspi/null";
+
+	/// A reference to no object, the same as [`ObjectRef::default`].
+	///
+	/// The owned name and path types cannot be built in a `const` context,
+	/// hence this is a function rather than an associated constant.
+	#[must_use]
+	pub fn null() -> Self {
 		ObjectRef {
-			name: UniqueName::from_static_str(":0.0").unwrap().into(),
-			path: ObjectPath::from_static_str("/org/a11y/atspi/accessible/null")
-				.unwrap()
-				.into(),
+			name: UniqueName::from_static_str_unchecked(Self::NULL_NAME).into(),
+			path: ObjectPath::from_static_str_unchecked(Self::NULL_PATH).into(),
 		}
 	}
+
+	/// Whether this refers to no object, i.e. its path is the null path.
+	///
+	/// Both [`ObjectRef::NULL_PATH`] and `/org/a11y/atspi/null`, which `at-spi2-core` uses, are considered null.
+	///
+	/// ```rust
+	/// # use atspi_common::ObjectRef;
+	/// assert!(ObjectRef::default().is_null());
+	/// ```
+	#[must_use]
+	pub fn is_null(&self) -> bool {
+		let path = self.path.as_str();
+		path == Self::NULL_PATH || path == Self::ATSPI_NULL_PATH
+	}
+}
+
+impl Default for ObjectRef {
+	fn default() -> Self {
+		ObjectRef::null()
+	}
+}
+
+#[cfg(test)]
+#[test]
+fn null_object_ref() {
+	assert!(ObjectRef::default().is_null());
+	assert!(ObjectRef::null().is_null());
+	let app_parent = ObjectRef {
+		name: ":1.7".try_into().unwrap(),
+		path: "/org/a11y/atspi/null".try_into().unwrap(),
+	};
+	assert!(app_parent.is_null());
+	let button = ObjectRef {
+		name: ":1.7".try_into().unwrap(),
+		path: "/org/a11y/atspi/accessible/1".try_into().unwrap(),
+	};
+	assert!(!button.is_null());
+}
+
+#[cfg(test)]
+#[test]
+fn equal_object_refs_hash_equal() {
+	use std::collections::HashSet;
+
+	let obj = |name: &str, path: &str| ObjectRef {
+		name: name.try_into().unwrap(),
+		path: path.try_into().unwrap(),
+	};
+	let refs = HashSet::from([
+		obj(":1.7", "/org/a11y/atspi/accessible/1"),
+		obj(":1.7", "/org/a11y/atspi/accessible/1"),
+		obj(":1.7", "/org/a11y/atspi/accessible/2"),
+		obj(":1.8", "/org/a11y/atspi/accessible/1"),
+	]);
+	assert_eq!(refs.len(), 3);
+	assert!(refs.contains(&obj(":1.7", "/org/a11y/atspi/accessible/1")));
 }
 
 #[cfg(test)]
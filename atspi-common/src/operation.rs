@@ -18,13 +18,15 @@ pub enum Operation {
 	Delete,
 }
 
+/// Parses the kind of a `TextChanged` or `ChildrenChanged` event,
+/// which toolkits spell in several ways, e.g. `"add"`, `"insert/system"` or `"remove"`.
 impl FromStr for Operation {
 	type Err = AtspiError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		match s {
 			"add" | "add/system" | "insert" | "insert/system" => Ok(Operation::Insert),
 			"delete" | "delete/system" | "remove" | "remove/system" => Ok(Operation::Delete),
-			_ => Err(AtspiError::KindMatch(format!("{s} is not a type of Operation"))),
+			_ => Err(AtspiError::Conversion("Unknown Operation")),
 		}
 	}
 }
@@ -37,3 +39,32 @@ impl fmt::Display for Operation {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::Operation;
+	use crate::AtspiError;
+
+	#[test]
+	fn display_round_trips() {
+		for operation in [Operation::Insert, Operation::Delete] {
+			assert_eq!(operation.to_string().parse::<Operation>().unwrap(), operation);
+		}
+	}
+
+	#[test]
+	fn parses_event_kinds() {
+		for kind in ["add", "add/system", "insert", "insert/system"] {
+			assert_eq!(kind.parse::<Operation>().unwrap(), Operation::Insert);
+		}
+		for kind in ["delete", "delete/system", "remove", "remove/system"] {
+			assert_eq!(kind.parse::<Operation>().unwrap(), Operation::Delete);
+		}
+	}
+
+	#[test]
+	fn unknown_kind_is_a_conversion_error() {
+		assert!(matches!("replace".parse::<Operation>(), Err(AtspiError::Conversion(_))));
+		assert!(matches!("Add".parse::<Operation>(), Err(AtspiError::Conversion(_))));
+	}
+}
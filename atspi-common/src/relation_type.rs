@@ -1,4 +1,6 @@
+use crate::AtspiError;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use zvariant::Type;
 
 /// Describes a relationship between one object and another.
@@ -118,3 +120,128 @@ pub enum RelationType {
 	/// Included in upstream [AT-SPI2-CORE](https://gitlab.gnome.org/GNOME/at-spi2-core) since 2.26.
 	ErrorFor,
 }
+
+impl TryFrom<u32> for RelationType {
+	type Error = AtspiError;
+
+	fn try_from(value: u32) -> Result<Self, Self::Error> {
+		#[allow(clippy::enum_glob_use)]
+		use RelationType::*;
+		let res = match value {
+			0 => Null,
+			1 => LabelFor,
+			2 => LabelledBy,
+			3 => ControllerFor,
+			4 => ControlledBy,
+			5 => MemberOf,
+			6 => TooltipFor,
+			7 => NodeChildOf,
+			8 => NodeParentOf,
+			9 => Extended,
+			10 => FlowsTo,
+			11 => FlowsFrom,
+			12 => SubwindowOf,
+			13 => Embeds,
+			14 => EmbeddedBy,
+			15 => PopupFor,
+			16 => ParentWindowOf,
+			17 => DescriptionFor,
+			18 => DescribedBy,
+			19 => Details,
+			20 => DetailsFor,
+			21 => ErrorMessage,
+			22 => ErrorFor,
+			_ => return Err(AtspiError::UnknownRelation(value)),
+		};
+		Ok(res)
+	}
+}
+
+const RELATION_NAMES: &[&str] = &[
+	"null",
+	"label-for",
+	"labelled-by",
+	"controller-for",
+	"controlled-by",
+	"member-of",
+	"tooltip-for",
+	"node-child-of",
+	"node-parent-of",
+	"extended",
+	"flows-to",
+	"flows-from",
+	"subwindow-of",
+	"embeds",
+	"embedded-by",
+	"popup-for",
+	"parent-window-of",
+	"description-for",
+	"described-by",
+	"details",
+	"details-for",
+	"error-message",
+	"error-for",
+];
+
+impl RelationType {
+	/// The name `libatspi` uses for the relation type, e.g. `"labelled-by"`.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		RELATION_NAMES[*self as usize]
+	}
+}
+
+impl std::fmt::Display for RelationType {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+/// Parses the name of [`RelationType::name`], e.g. `"controller-for"`.
+impl FromStr for RelationType {
+	type Err = AtspiError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let index = RELATION_NAMES
+			.iter()
+			.position(|name| *name == s)
+			.ok_or(AtspiError::Conversion("Unknown RelationType name"))?;
+		RelationType::try_from(u32::try_from(index).expect("fewer than u32::MAX relation types"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::RelationType;
+	use crate::AtspiError;
+	use zvariant::{serialized::Context, to_bytes, LE};
+
+	#[test]
+	fn try_from_u32_matches_serialization() {
+		let ctxt = Context::new_dbus(LE, 0);
+		for value in 0..=22 {
+			let relation = RelationType::try_from(value).unwrap();
+			let encoded = to_bytes(ctxt, &relation).unwrap();
+			assert_eq!(encoded.bytes(), value.to_le_bytes());
+		}
+		assert!(matches!(RelationType::try_from(23), Err(AtspiError::UnknownRelation(23))));
+	}
+
+	#[test]
+	fn relation_name_round_trip() {
+		for value in 0..=22 {
+			let relation = RelationType::try_from(value).unwrap();
+			assert_eq!(relation.name().parse::<RelationType>().unwrap(), relation);
+			assert_eq!(relation.to_string(), relation.name());
+		}
+		assert_eq!(RelationType::LabelledBy.name(), "labelled-by");
+		assert_eq!(RelationType::ControllerFor.name(), "controller-for");
+		assert_eq!(RelationType::ParentWindowOf.name(), "parent-window-of");
+	}
+
+	#[test]
+	fn relation_from_unknown_name() {
+		assert!(matches!("labeled-by".parse::<RelationType>(), Err(AtspiError::Conversion(_))));
+		assert!(matches!("LabelledBy".parse::<RelationType>(), Err(AtspiError::Conversion(_))));
+	}
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use zvariant::Type;
 
 use crate::AtspiError;
@@ -608,6 +609,177 @@ impl Role {
 	pub fn name(&self) -> &'static str {
 		ROLE_NAMES[*self as usize]
 	}
+
+	/// Get the role from its readable, English name, the inverse of [`Role::name`].
+	///
+	/// ```rust
+	/// # use atspi_common::Role;
+	/// assert_eq!(Role::from_name("page tab list"), Some(Role::PageTabList));
+	/// assert_eq!(Role::from_name("PageTabList"), None);
+	/// ```
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Role> {
+		let index = ROLE_NAMES.iter().position(|role_name| *role_name == name)?;
+		Role::try_from(u32::try_from(index).ok()?).ok()
+	}
+
+	/// Whether the user operates an object of this role directly, e.g. by clicking, typing or dragging.
+	///
+	/// These are buttons ([`Role::Button`], [`Role::ToggleButton`], [`Role::RadioButton`],
+	/// [`Role::CheckBox`], [`Role::PushButtonMenu`]), menu items, links, page tabs,
+	/// items of lists and trees, value controls ([`Role::Slider`], [`Role::SpinButton`],
+	/// [`Role::ScrollBar`], [`Role::Dial`]), text fields ([`Role::Entry`], [`Role::PasswordText`],
+	/// [`Role::Autocomplete`], [`Role::Editbar`]), combo boxes and chooser dialogs.
+	///
+	/// ```rust
+	/// # use atspi_common::Role;
+	/// assert!(Role::CheckBox.is_interactive());
+	/// assert!(!Role::Label.is_interactive());
+	/// ```
+	#[must_use]
+	pub fn is_interactive(&self) -> bool {
+		matches!(
+			self,
+			Role::Button
+				| Role::ToggleButton
+				| Role::RadioButton
+				| Role::CheckBox
+				| Role::PushButtonMenu
+				| Role::MenuItem
+				| Role::CheckMenuItem
+				| Role::RadioMenuItem
+				| Role::TearoffMenuItem
+				| Role::Link | Role::PageTab
+				| Role::ListItem
+				| Role::TreeItem
+				| Role::Slider
+				| Role::SpinButton
+				| Role::ScrollBar
+				| Role::Dial | Role::Entry
+				| Role::PasswordText
+				| Role::Autocomplete
+				| Role::Editbar
+				| Role::ComboBox
+				| Role::ColorChooser
+				| Role::DateEditor
+				| Role::FileChooser
+				| Role::FontChooser
+		)
+	}
+
+	/// Whether an object of this role mainly groups other objects.
+	///
+	/// These are windows ([`Role::Window`], [`Role::Frame`], [`Role::Dialog`], [`Role::InternalFrame`],
+	/// [`Role::Application`]), layout panes ([`Role::Panel`], [`Role::Filler`], [`Role::ScrollPane`],
+	/// [`Role::SplitPane`], [`Role::Viewport`] and the like), semantic groups ([`Role::Grouping`],
+	/// [`Role::Section`], [`Role::Form`], [`Role::Landmark`]), and the parents of items:
+	/// menus, bars, lists, tables, trees and page tab lists.
+	///
+	/// ```rust
+	/// # use atspi_common::Role;
+	/// assert!(Role::Panel.is_container());
+	/// assert!(!Role::Button.is_container());
+	/// ```
+	#[must_use]
+	pub fn is_container(&self) -> bool {
+		matches!(
+			self,
+			Role::Window
+				| Role::Frame
+				| Role::Dialog
+				| Role::InternalFrame
+				| Role::DesktopFrame
+				| Role::Application
+				| Role::Panel
+				| Role::Filler
+				| Role::ScrollPane
+				| Role::SplitPane
+				| Role::LayeredPane
+				| Role::RootPane
+				| Role::GlassPane
+				| Role::OptionPane
+				| Role::DirectoryPane
+				| Role::Viewport
+				| Role::HTMLContainer
+				| Role::Grouping
+				| Role::Section
+				| Role::Form | Role::Landmark
+				| Role::Menu | Role::MenuBar
+				| Role::PopupMenu
+				| Role::ToolBar
+				| Role::StatusBar
+				| Role::List | Role::ListBox
+				| Role::Table
+				| Role::TableRow
+				| Role::Tree | Role::TreeTable
+				| Role::PageTabList
+				| Role::DescriptionList
+		)
+	}
+
+	/// Whether an object of this role mainly presents text.
+	///
+	/// These are blocks of text ([`Role::Text`], [`Role::Paragraph`], [`Role::Heading`],
+	/// [`Role::BlockQuote`], [`Role::Static`] and the like), labels and captions, editable text fields,
+	/// [`Role::Terminal`], and inline text such as [`Role::Mark`], [`Role::Subscript`] or [`Role::Suggestion`].
+	///
+	/// Text fields are both text and [interactive](Role::is_interactive).
+	///
+	/// ```rust
+	/// # use atspi_common::Role;
+	/// assert!(Role::Paragraph.is_text());
+	/// assert!(!Role::Image.is_text());
+	/// ```
+	#[must_use]
+	pub fn is_text(&self) -> bool {
+		matches!(
+			self,
+			Role::Text
+				| Role::Paragraph
+				| Role::Heading
+				| Role::BlockQuote
+				| Role::Static
+				| Role::Comment
+				| Role::Definition
+				| Role::Footnote
+				| Role::Caption
+				| Role::Label
+				| Role::AcceleratorLabel
+				| Role::DescriptionTerm
+				| Role::DescriptionValue
+				| Role::Entry
+				| Role::PasswordText
+				| Role::Autocomplete
+				| Role::Editbar
+				| Role::Terminal
+				| Role::Mark | Role::Subscript
+				| Role::Superscript
+				| Role::ContentDeletion
+				| Role::ContentInsertion
+				| Role::Suggestion
+		)
+	}
+}
+
+/// Parses either the readable name of [`Role::name`] (`"page tab list"`),
+/// or the name of the variant (`"PageTabList"`), ignoring case and surrounding whitespace.
+///
+/// The older AT-SPI spelling `"push button"` (`"PushButton"`) is accepted for [`Role::Button`].
+impl FromStr for Role {
+	type Err = AtspiError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+		if s.eq_ignore_ascii_case("push button") || s.eq_ignore_ascii_case("PushButton") {
+			return Ok(Role::Button);
+		}
+		(0..)
+			.map_while(|index| Role::try_from(index).ok())
+			.find(|role| {
+				role.name().eq_ignore_ascii_case(s) || format!("{role:?}").eq_ignore_ascii_case(s)
+			})
+			.ok_or(AtspiError::Conversion("Unknown Role name"))
+	}
 }
 
 impl std::fmt::Display for Role {
@@ -619,8 +791,10 @@ impl std::fmt::Display for Role {
 #[cfg(test)]
 pub mod tests {
 	use super::Role;
+	use crate::AtspiError;
+	use zbus_lockstep::method_return_signature;
 	use zvariant::serialized::Context;
-	use zvariant::{to_bytes, LE};
+	use zvariant::{to_bytes, Type, LE};
 
 	const HIGHEST_ROLE_VALUE: u32 = 129;
 
@@ -647,4 +821,95 @@ pub mod tests {
 			);
 		}
 	}
+
+	#[test]
+	fn role_name_round_trip() {
+		for role_num in 0..=HIGHEST_ROLE_VALUE {
+			let role = Role::try_from(role_num).unwrap();
+			assert_eq!(Role::from_name(role.name()), Some(role));
+		}
+		assert_eq!(Role::from_name("no such role"), None);
+	}
+
+	#[test]
+	fn role_from_str_accepts_both_spellings() {
+		assert_eq!("page tab list".parse::<Role>().unwrap(), Role::PageTabList);
+		assert_eq!("PageTabList".parse::<Role>().unwrap(), Role::PageTabList);
+		assert_eq!("pagetablist".parse::<Role>().unwrap(), Role::PageTabList);
+		assert_eq!("Page Tab List".parse::<Role>().unwrap(), Role::PageTabList);
+		assert_eq!("push button".parse::<Role>().unwrap(), Role::Button);
+		assert_eq!("PushButton".parse::<Role>().unwrap(), Role::Button);
+		for role_num in 0..=HIGHEST_ROLE_VALUE {
+			let role = Role::try_from(role_num).unwrap();
+			assert_eq!(role.name().parse::<Role>().unwrap(), role);
+			assert_eq!(format!("{role:?}").parse::<Role>().unwrap(), role);
+		}
+	}
+
+	#[test]
+	fn role_from_str_trims_whitespace() {
+		assert_eq!("  check box\n".parse::<Role>().unwrap(), Role::CheckBox);
+		assert_eq!("\tCheckBox ".parse::<Role>().unwrap(), Role::CheckBox);
+	}
+
+	#[test]
+	fn role_from_str_rejects_unknown_names() {
+		assert!(matches!("no such role".parse::<Role>(), Err(AtspiError::Conversion(_))));
+		assert!(matches!("".parse::<Role>(), Err(AtspiError::Conversion(_))));
+		assert!(matches!("check  box".parse::<Role>(), Err(AtspiError::Conversion(_))));
+	}
+
+	#[test]
+	fn validate_role_signature() {
+		let signature =
+			method_return_signature!(member: "GetRole", interface: "org.a11y.atspi.Accessible");
+		assert_eq!(Role::signature(), signature);
+	}
+
+	/// Every role documented in the IDL, like `42 - ATSPI_ROLE_LABEL: ...`, maps to the `Role` of that number.
+	#[test]
+	fn role_numbers_match_idl() {
+		let idl = include_str!("../xml/Accessible.xml");
+		let mut documented = 0;
+		for line in idl.lines() {
+			let Some((number, rest)) = line.trim().split_once(" - ATSPI_ROLE_") else {
+				continue;
+			};
+			let Some((name, _)) = rest.split_once(':') else {
+				continue;
+			};
+			let number: u32 = number.parse().expect("role number in IDL is not an integer");
+			let role = Role::try_from(number)
+				.unwrap_or_else(|_| panic!("IDL role {number} has no `Role`"));
+			assert_eq!(role as u32, number);
+			assert_eq!(role.name(), name.to_lowercase().replace('_', " "));
+			documented += 1;
+		}
+		assert_eq!(documented, HIGHEST_ROLE_VALUE + 1);
+		assert!(Role::try_from(HIGHEST_ROLE_VALUE + 1).is_err());
+	}
+
+	#[test]
+	fn unknown_role_number_is_reported() {
+		assert!(matches!(Role::try_from(9999), Err(AtspiError::UnknownRole(9999))));
+	}
+
+	#[test]
+	fn role_categories() {
+		for role in [Role::Button, Role::CheckBox, Role::Slider, Role::Entry, Role::Link] {
+			assert!(role.is_interactive(), "{role} is interactive");
+			assert!(!role.is_container(), "{role} is not a container");
+		}
+		for role in [Role::Panel, Role::Filler, Role::ScrollPane, Role::Frame, Role::List] {
+			assert!(role.is_container(), "{role} is a container");
+			assert!(!role.is_interactive(), "{role} is not interactive");
+			assert!(!role.is_text(), "{role} is not text");
+		}
+		for role in [Role::Paragraph, Role::Heading, Role::Label, Role::Entry] {
+			assert!(role.is_text(), "{role} is text");
+		}
+		for role in [Role::Invalid, Role::Unknown, Role::Image, Role::Separator] {
+			assert!(!role.is_interactive() && !role.is_container() && !role.is_text());
+		}
+	}
 }
@@ -7,6 +7,8 @@ use serde::{
 use std::{convert::Infallible, fmt, str::FromStr};
 use zvariant::{Signature, Type};
 
+use crate::AtspiError;
+
 /// Used by various interfaces indicating every possible state
 /// of an accessibility object.
 #[bitflags]
@@ -337,6 +339,135 @@ impl From<&str> for State {
 	}
 }
 
+impl State {
+	/// The number of this state in AT-SPI, which is its bit index in a [`StateSet`].
+	#[must_use]
+	pub fn index(self) -> u32 {
+		(self as u64).trailing_zeros()
+	}
+
+	/// Whether the state is momentary, i.e. it commonly flips while the user interacts with the object,
+	/// as opposed to a structural state, which describes what kind of object it is.
+	///
+	/// Announcements of transient states are candidates for debouncing.
+	///
+	/// | Transient | Structural |
+	/// |-----------|------------|
+	/// | [`Active`], [`Armed`], [`Busy`], [`Pressed`], [`Animated`] | [`Invalid`], [`Editable`], [`ReadOnly`], [`Required`] |
+	/// | [`Focused`], [`Selected`], [`Checked`], [`Indeterminate`] | [`Focusable`], [`Selectable`], [`Checkable`], [`Multiselectable`] |
+	/// | [`Expanded`], [`Collapsed`], [`Visited`], [`InvalidEntry`] | [`Expandable`], [`HasPopup`], [`HasTooltip`], [`IsDefault`] |
+	/// | [`Enabled`], [`Sensitive`], [`Showing`], [`Visible`] | [`Horizontal`], [`Vertical`], [`SingleLine`], [`MultiLine`] |
+	/// | [`Iconified`], [`Truncated`], [`Stale`], [`Defunct`] | [`Modal`], [`Opaque`], [`Resizable`], [`State::Transient`] |
+	/// | | [`ManagesDescendants`], [`SupportsAutocompletion`], [`SelectableText`] |
+	///
+	/// Note that [`State::Transient`], an object which is not kept in the tree for long, is itself structural.
+	///
+	/// [`Active`]: State::Active
+	/// [`Armed`]: State::Armed
+	/// [`Busy`]: State::Busy
+	/// [`Pressed`]: State::Pressed
+	/// [`Animated`]: State::Animated
+	/// [`Focused`]: State::Focused
+	/// [`Selected`]: State::Selected
+	/// [`Checked`]: State::Checked
+	/// [`Indeterminate`]: State::Indeterminate
+	/// [`Expanded`]: State::Expanded
+	/// [`Collapsed`]: State::Collapsed
+	/// [`Visited`]: State::Visited
+	/// [`InvalidEntry`]: State::InvalidEntry
+	/// [`Enabled`]: State::Enabled
+	/// [`Sensitive`]: State::Sensitive
+	/// [`Showing`]: State::Showing
+	/// [`Visible`]: State::Visible
+	/// [`Iconified`]: State::Iconified
+	/// [`Truncated`]: State::Truncated
+	/// [`Stale`]: State::Stale
+	/// [`Defunct`]: State::Defunct
+	/// [`Invalid`]: State::Invalid
+	/// [`Editable`]: State::Editable
+	/// [`ReadOnly`]: State::ReadOnly
+	/// [`Required`]: State::Required
+	/// [`Focusable`]: State::Focusable
+	/// [`Selectable`]: State::Selectable
+	/// [`Checkable`]: State::Checkable
+	/// [`Multiselectable`]: State::Multiselectable
+	/// [`Expandable`]: State::Expandable
+	/// [`HasPopup`]: State::HasPopup
+	/// [`HasTooltip`]: State::HasTooltip
+	/// [`IsDefault`]: State::IsDefault
+	/// [`Horizontal`]: State::Horizontal
+	/// [`Vertical`]: State::Vertical
+	/// [`SingleLine`]: State::SingleLine
+	/// [`MultiLine`]: State::MultiLine
+	/// [`Modal`]: State::Modal
+	/// [`Opaque`]: State::Opaque
+	/// [`Resizable`]: State::Resizable
+	/// [`ManagesDescendants`]: State::ManagesDescendants
+	/// [`SupportsAutocompletion`]: State::SupportsAutocompletion
+	/// [`SelectableText`]: State::SelectableText
+	#[must_use]
+	pub fn is_transient(self) -> bool {
+		match self {
+			State::Active
+			| State::Armed
+			| State::Busy
+			| State::Pressed
+			| State::Animated
+			| State::Focused
+			| State::Selected
+			| State::Checked
+			| State::Indeterminate
+			| State::Expanded
+			| State::Collapsed
+			| State::Visited
+			| State::InvalidEntry
+			| State::Enabled
+			| State::Sensitive
+			| State::Showing
+			| State::Visible
+			| State::Iconified
+			| State::Truncated
+			| State::Stale
+			| State::Defunct => true,
+			State::Invalid
+			| State::Editable
+			| State::ReadOnly
+			| State::Required
+			| State::Focusable
+			| State::Selectable
+			| State::Checkable
+			| State::Multiselectable
+			| State::Expandable
+			| State::HasPopup
+			| State::HasTooltip
+			| State::IsDefault
+			| State::Horizontal
+			| State::Vertical
+			| State::SingleLine
+			| State::MultiLine
+			| State::Modal
+			| State::Opaque
+			| State::Resizable
+			| State::Transient
+			| State::ManagesDescendants
+			| State::SupportsAutocompletion
+			| State::SelectableText => false,
+		}
+	}
+}
+
+impl TryFrom<u32> for State {
+	type Error = AtspiError;
+
+	/// Get the state from its number in AT-SPI, which is its bit index in a [`StateSet`].
+	fn try_from(index: u32) -> Result<Self, Self::Error> {
+		1u64.checked_shl(index)
+			.and_then(|bit| BitFlags::<State>::from_bits(bit).ok())
+			.and_then(BitFlags::exactly_one)
+			.ok_or(AtspiError::UnknownState(index))
+	}
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 /// The bitflag representation of all states an object may have.
@@ -545,12 +676,28 @@ impl std::ops::BitAndAssign for StateSet {
 		self.0 = self.0 & other.0;
 	}
 }
+/// The states of `self` which are not in `other`.
+impl std::ops::Sub for StateSet {
+	type Output = StateSet;
+
+	fn sub(self, other: Self) -> Self::Output {
+		StateSet(self.0 & !other.0)
+	}
+}
+impl std::ops::SubAssign for StateSet {
+	fn sub_assign(&mut self, other: Self) {
+		*self = *self - other;
+	}
+}
 
 #[cfg(test)]
 mod tests {
 	use super::{State, StateSet};
+	use crate::AtspiError;
+	use enumflags2::BitFlags;
+	use zbus_lockstep::method_return_signature;
 	use zvariant::serialized::{Context, Data};
-	use zvariant::{to_bytes, LE};
+	use zvariant::{to_bytes, Type, LE};
 
 	#[test]
 	fn serialize_empty_state_set() {
@@ -707,4 +854,75 @@ mod tests {
 		assert!(states.contains(&State::Focused));
 		assert!(states.contains(&State::Focusable));
 	}
+
+	#[test]
+	fn iter_empty_and_full_stateset() {
+		assert_eq!(StateSet::empty().iter().next(), None);
+		let states: Vec<State> = StateSet::new(BitFlags::<State>::all()).iter().collect();
+		assert_eq!(states.len(), BitFlags::<State>::all().len());
+		for (index, state) in states.into_iter().enumerate() {
+			assert_eq!(state.index(), u32::try_from(index).unwrap());
+		}
+	}
+
+	#[test]
+	fn validate_state_set_signature() {
+		let signature =
+			method_return_signature!(member: "GetState", interface: "org.a11y.atspi.Accessible");
+		assert_eq!(StateSet::signature(), signature);
+	}
+
+	/// Every state documented in the IDL, like `12 - ATSPI_STATE_FOCUSED: ...`, maps to the `State` of that number.
+	#[test]
+	fn state_numbers_match_idl() {
+		let idl = include_str!("../xml/Accessible.xml");
+		let mut documented = 0;
+		for line in idl.lines() {
+			let Some((number, rest)) = line.trim().split_once(" - ATSPI_STATE_") else {
+				continue;
+			};
+			let Some((name, _)) = rest.split_once(':') else {
+				continue;
+			};
+			let number: u32 = number.parse().expect("state number in IDL is not an integer");
+			let state = State::try_from(number)
+				.unwrap_or_else(|_| panic!("IDL state {number} has no `State`"));
+			assert_eq!(state.index(), number);
+			assert_eq!(state.to_string(), name.to_lowercase().replace('_', "-"));
+			assert_eq!(StateSet::new(state).bits(), 1 << number);
+			documented += 1;
+		}
+		assert_eq!(documented, BitFlags::<State>::all().len());
+		assert!(State::try_from(u32::try_from(documented).unwrap()).is_err());
+		assert!(matches!(State::try_from(64), Err(AtspiError::UnknownState(64))));
+	}
+
+	#[test]
+	fn state_set_operators() {
+		let focusable = StateSet::new(State::Focusable);
+		let enabled = StateSet::new(State::Enabled);
+		assert_eq!((focusable | enabled) & enabled, enabled);
+		assert_eq!((focusable | enabled) ^ enabled, focusable);
+		assert_eq!((focusable | enabled) - enabled, focusable);
+		assert_eq!(focusable - enabled, focusable);
+
+		let mut set = focusable;
+		set |= enabled;
+		assert_eq!(set, StateSet::new(State::Focusable | State::Enabled));
+		set -= focusable;
+		assert_eq!(set, enabled);
+		set &= focusable;
+		assert!(set.is_empty());
+		set ^= enabled;
+		assert_eq!(set, enabled);
+	}
+
+	#[test]
+	fn transient_and_structural_states() {
+		assert!(State::Focused.is_transient());
+		assert!(State::Selected.is_transient());
+		assert!(!State::Editable.is_transient());
+		assert!(!State::MultiLine.is_transient());
+		assert!(!State::Transient.is_transient());
+	}
 }
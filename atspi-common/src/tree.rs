@@ -0,0 +1,265 @@
+//! A mirror of an application's accessibility tree, kept up to date from `Cache` events.
+
+use crate::events::CacheEvents;
+use crate::{CacheItem, ObjectRef};
+use std::collections::HashMap;
+
+/// The accessible objects of an application, as announced by its `Cache:AddAccessible`
+/// and `Cache:RemoveAccessible` signals.
+///
+/// Seed the tree with the items of `CacheProxy::get_items`, by wrapping them in `AddAccessibleEvent`s,
+/// then [`Tree::apply`] each cache event as it arrives.
+///
+/// Removing an object does not remove its descendants: they stay in the tree as orphans,
+/// since applications usually announce the removal of each of them separately.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tree {
+	items: HashMap<ObjectRef, CacheItem>,
+	/// The children of each parent, in order.
+	///
+	/// A list may name objects which are not in the tree (yet), as legacy items list all of their children.
+	children: HashMap<ObjectRef, Vec<ObjectRef>>,
+	root: Option<ObjectRef>,
+}
+
+impl Tree {
+	/// An empty tree.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Updates the tree with the change announced by `event`.
+	///
+	/// An added object replaces any object previously stored under the same reference.
+	/// Removing an object which is not in the tree does nothing.
+	pub fn apply(&mut self, event: &CacheEvents) {
+		match event {
+			CacheEvents::Add(add) => {
+				let item = add.node_added.clone();
+				self.unlink(&item.object);
+				let siblings = self.children.entry(item.parent.clone()).or_default();
+				if !siblings.contains(&item.object) {
+					// Keep the siblings ordered by their index in the parent.
+					let items = &self.items;
+					let pos = siblings
+						.iter()
+						.position(|sibling| {
+							items.get(sibling).is_some_and(|s| s.index > item.index)
+						})
+						.unwrap_or(siblings.len());
+					siblings.insert(pos, item.object.clone());
+				}
+				self.insert(item);
+			}
+			CacheEvents::LegacyAdd(add) => {
+				// Legacy items carry no index in parent, but list their children in order.
+				let item = CacheItem::from(add.node_added.clone());
+				if self
+					.items
+					.get(&item.object)
+					.is_some_and(|old| old.parent != item.parent)
+				{
+					self.unlink(&item.object);
+				}
+				let siblings = self.children.entry(item.parent.clone()).or_default();
+				if !siblings.contains(&item.object) {
+					siblings.push(item.object.clone());
+				}
+				self.children
+					.insert(item.object.clone(), add.node_added.children.clone());
+				self.insert(item);
+			}
+			CacheEvents::Remove(remove) => {
+				self.unlink(&remove.node_removed);
+				self.items.remove(&remove.node_removed);
+				if self.root.as_ref() == Some(&remove.node_removed) {
+					self.root = None;
+				}
+			}
+		}
+	}
+
+	/// Removes `obj` from the children of its parent, if it is in the tree.
+	fn unlink(&mut self, obj: &ObjectRef) {
+		let Some(item) = self.items.get(obj) else {
+			return;
+		};
+		if let Some(siblings) = self.children.get_mut(&item.parent) {
+			siblings.retain(|sibling| sibling != obj);
+		}
+	}
+
+	fn insert(&mut self, item: CacheItem) {
+		if item.object == item.app {
+			self.root = Some(item.object.clone());
+		} else if self.root.as_ref() == Some(&item.object) {
+			self.root = None;
+		}
+		self.items.insert(item.object.clone(), item);
+	}
+
+	/// The cached description of `obj`, if it is in the tree.
+	#[must_use]
+	pub fn get(&self, obj: &ObjectRef) -> Option<&CacheItem> {
+		self.items.get(obj)
+	}
+
+	/// The objects in the tree whose parent is `parent`, ordered by their index in the parent,
+	/// or for legacy items, in the order their parent lists them.
+	///
+	/// This works for any `parent`, including one which has been removed from the tree.
+	#[must_use]
+	pub fn children_of(&self, parent: &ObjectRef) -> Vec<&CacheItem> {
+		let Some(children) = self.children.get(parent) else {
+			return Vec::new();
+		};
+		children
+			.iter()
+			.filter_map(|child| self.items.get(child))
+			.filter(|item| &item.parent == parent)
+			.collect()
+	}
+
+	/// The application's root object: the object which is its own application.
+	///
+	/// Returns `None` until the root has been added.
+	#[must_use]
+	pub fn root(&self) -> Option<&CacheItem> {
+		self.root.as_ref().and_then(|root| self.items.get(root))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Tree;
+	use crate::events::{
+		cache::{AddAccessibleEvent, LegacyAddAccessibleEvent, RemoveAccessibleEvent},
+		CacheEvents,
+	};
+	use crate::{CacheItem, LegacyCacheItem, ObjectRef, Role};
+
+	fn obj(name: &str) -> ObjectRef {
+		ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/{name}").try_into().unwrap(),
+		}
+	}
+
+	fn add(name: &str, parent: &str, index: i32, role: Role) -> CacheEvents {
+		let node_added = CacheItem {
+			object: obj(name),
+			app: obj("root"),
+			parent: if parent.is_empty() { ObjectRef::null() } else { obj(parent) },
+			index,
+			role,
+			..CacheItem::default()
+		};
+		CacheEvents::Add(AddAccessibleEvent { item: obj("cache"), node_added })
+	}
+
+	fn remove(name: &str) -> CacheEvents {
+		CacheEvents::Remove(RemoveAccessibleEvent { item: obj("cache"), node_removed: obj(name) })
+	}
+
+	/// ```text
+	/// root
+	/// └── window
+	///     ├── label:  index 0
+	///     └── button: index 1
+	/// ```
+	fn tree() -> Tree {
+		let mut tree = Tree::new();
+		for event in [
+			add("button", "window", 1, Role::Button),
+			add("root", "", -1, Role::Application),
+			add("label", "window", 0, Role::Label),
+			add("window", "root", 0, Role::Frame),
+		] {
+			tree.apply(&event);
+		}
+		tree
+	}
+
+	fn paths(items: &[&CacheItem]) -> Vec<String> {
+		items.iter().map(|item| item.object.path.to_string()).collect()
+	}
+
+	#[test]
+	fn added_objects_form_a_tree() {
+		let tree = tree();
+		assert_eq!(tree.root().unwrap().object, obj("root"));
+		assert_eq!(paths(&tree.children_of(&obj("root"))), ["/org/a11y/atspi/accessible/window"]);
+		assert_eq!(
+			paths(&tree.children_of(&obj("window"))),
+			["/org/a11y/atspi/accessible/label", "/org/a11y/atspi/accessible/button"]
+		);
+		assert_eq!(tree.get(&obj("button")).unwrap().role, Role::Button);
+		assert!(tree.children_of(&obj("button")).is_empty());
+	}
+
+	#[test]
+	fn removed_object_orphans_its_children() {
+		let mut tree = tree();
+		tree.apply(&remove("window"));
+		assert!(tree.get(&obj("window")).is_none());
+		assert!(tree.children_of(&obj("root")).is_empty());
+		assert_eq!(tree.get(&obj("label")).unwrap().parent, obj("window"));
+		assert_eq!(tree.children_of(&obj("window")).len(), 2);
+
+		tree.apply(&remove("window"));
+		tree.apply(&remove("root"));
+		assert!(tree.root().is_none());
+	}
+
+	#[test]
+	fn legacy_items_are_added() {
+		let mut tree = Tree::new();
+		let node_added = LegacyCacheItem {
+			object: obj("root"),
+			app: obj("root"),
+			role: Role::Application,
+			..LegacyCacheItem::default()
+		};
+		tree.apply(&CacheEvents::LegacyAdd(LegacyAddAccessibleEvent {
+			item: obj("cache"),
+			node_added,
+		}));
+		assert_eq!(tree.root().unwrap().role, Role::Application);
+	}
+
+	fn legacy_add(name: &str, parent: &str, children: &[&str]) -> CacheEvents {
+		let node_added = LegacyCacheItem {
+			object: obj(name),
+			app: obj("root"),
+			parent: obj(parent),
+			children: children.iter().map(|child| obj(child)).collect(),
+			..LegacyCacheItem::default()
+		};
+		CacheEvents::LegacyAdd(LegacyAddAccessibleEvent { item: obj("cache"), node_added })
+	}
+
+	#[test]
+	fn legacy_children_keep_their_parents_order() {
+		let mut tree = Tree::new();
+		for event in [
+			legacy_add("ok", "dialog", &[]),
+			legacy_add("dialog", "root", &["label", "ok", "cancel"]),
+			legacy_add("cancel", "dialog", &[]),
+			legacy_add("label", "dialog", &[]),
+			// Updating a child keeps its place.
+			legacy_add("ok", "dialog", &[]),
+		] {
+			tree.apply(&event);
+		}
+		let order = [
+			"/org/a11y/atspi/accessible/label",
+			"/org/a11y/atspi/accessible/ok",
+			"/org/a11y/atspi/accessible/cancel",
+		];
+		assert_eq!(paths(&tree.children_of(&obj("dialog"))), order);
+
+		tree.apply(&remove("ok"));
+		assert_eq!(paths(&tree.children_of(&obj("dialog"))), [order[0], order[2]]);
+	}
+}
@@ -0,0 +1,79 @@
+//! Counts the heap allocations made while parsing event bodies.
+
+use atspi_common::events::{EventBodyBorrowed, EventBodyOwned};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts allocations made on the current thread, so tests running in parallel do not interfere.
+struct CountingAllocator;
+
+thread_local! {
+	static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: defers to the system allocator; the thread-local counter never allocates itself.
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, returning the number of allocations it made.
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+	let before = ALLOCATIONS.with(Cell::get);
+	let result = f();
+	let after = ALLOCATIONS.with(Cell::get);
+	drop(result);
+	after - before
+}
+
+fn message(member: &str, body: &EventBodyOwned) -> zbus::Message {
+	zbus::Message::signal("/org/a11y/atspi/accessible/1", "org.a11y.atspi.Event.Object", member)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(body)
+		.unwrap()
+}
+
+/// Deserializing any body costs zvariant a fixed number of allocations, which is the baseline here.
+/// On top of that, the owned body allocates for each non-empty string, while the borrowed one does not.
+#[test]
+fn borrowed_body_makes_no_allocations_of_its_own() {
+	let empty = message("StateChanged", &EventBodyOwned::default());
+	let state_changed = message(
+		"StateChanged",
+		&EventBodyOwned { kind: "focused".into(), detail1: 1, ..Default::default() },
+	);
+	let text_changed = message(
+		"TextChanged",
+		&EventBodyOwned {
+			kind: "insert".into(),
+			any_data: zvariant::Value::from("inserted text").try_into().unwrap(),
+			..Default::default()
+		},
+	);
+	let borrowed = |msg: &zbus::Message| {
+		let body = msg.body();
+		allocations(|| body.deserialize::<EventBodyBorrowed>().unwrap())
+	};
+	let owned = |msg: &zbus::Message| {
+		let body = msg.body();
+		allocations(|| body.deserialize::<EventBodyOwned>().unwrap())
+	};
+
+	let baseline = owned(&empty);
+	assert_eq!(borrowed(&empty), baseline);
+	assert_eq!(borrowed(&state_changed), baseline);
+	assert_eq!(borrowed(&text_changed), baseline);
+	assert!(owned(&state_changed) > baseline);
+	assert!(owned(&text_changed) > owned(&state_changed));
+}
@@ -9,25 +9,89 @@ compile_error!("You must specify at least one of the `async-std` or `tokio` feat
 
 pub use atspi_common as common;
 
+mod registration;
+pub use registration::{EventRegistration, RegistrationGuard};
+
 use atspi_proxies::{
+	accessible::AccessibleProxy,
 	bus::{BusProxy, StatusProxy},
+	proxy_ext::ObjectRefProxyExt,
 	registry::RegistryProxy,
 };
 use common::error::AtspiError;
 use common::events::{
-	BusProperties, Event, EventProperties, HasMatchRule, HasRegistryEventString, MessageConversion,
+	AvailableEvent, BusProperties, Event, EventProperties, HasMatchRule, HasRegistryEventString,
+	MessageConversion, TimestampedEvent,
 };
+use common::{MatchType, ObjectMatchRule, ObjectRef, SortOrder, State, TextSelection};
 use futures_lite::stream::{Stream, StreamExt};
+use std::future::Future;
 use std::ops::Deref;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
 use zbus::{fdo::DBusProxy, Address, MatchRule, MessageStream, MessageType};
 
 /// A wrapper for results whose error type is [`AtspiError`].
 pub type AtspiResult<T> = std::result::Result<T, AtspiError>;
 
+/// Registers several event types on an [`AccessibilityConnection`] with a single `.await`.
+///
+/// `register_events!(conn, A, B)` expands to a future which calls
+/// [`AccessibilityConnection::register_event`] for `A`, then for `B`, in the order given.
+/// It stops at the first error and returns it; event types registered before the error stay registered.
+///
+/// ```rust
+/// use atspi_connection::{register_events, AccessibilityConnection};
+/// use atspi_connection::common::events::{ObjectEvents, WindowEvents};
+/// # tokio_test::block_on(async {
+/// let connection = AccessibilityConnection::new().await.unwrap();
+/// register_events!(connection, ObjectEvents, WindowEvents).await.unwrap();
+/// # })
+/// ```
+///
+/// See [`EventRegistration`] to register a set of event types, and deregister them again, as a unit.
+#[macro_export]
+macro_rules! register_events {
+	($conn:expr, $($event:ty),+ $(,)?) => {
+		async {
+			let conn: &$crate::AccessibilityConnection = &$conn;
+			$(conn.register_event::<$event>().await?;)+
+			::core::result::Result::<(), $crate::common::AtspiError>::Ok(())
+		}
+	};
+}
+
 /// A connection to the at-spi bus
 pub struct AccessibilityConnection {
 	registry: RegistryProxy<'static>,
 	dbus_proxy: DBusProxy<'static>,
+	/// The address passed to [`Self::from_address`], or `None` if it is looked up on the session bus.
+	bus_address: Option<Address>,
+	/// Match rules added through this connection, re-added by [`Self::reconnect`].
+	match_rules: Mutex<Vec<&'static str>>,
+	/// Registry events registered through this connection, re-registered by [`Self::reconnect`].
+	registry_events: Mutex<Vec<&'static str>>,
+	/// See [`Self::with_method_timeout`].
+	method_timeout: Option<Duration>,
+}
+
+/// Locks `list`, ignoring poisoning: the lists are only ever inserted into or removed from.
+fn lock<T>(list: &Mutex<T>) -> MutexGuard<'_, T> {
+	list.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Removes one occurrence of `item` from `list`, mirroring how the bus counts duplicate registrations.
+///
+/// Returns whether `item` was in `list`.
+fn remove_one(list: &Mutex<Vec<&'static str>>, item: &str) -> bool {
+	let mut list = lock(list);
+	match list.iter().position(|existing| *existing == item) {
+		Some(pos) => {
+			list.remove(pos);
+			true
+		}
+		None => false,
+	}
 }
 
 impl AccessibilityConnection {
@@ -37,6 +101,13 @@ impl AccessibilityConnection {
 	/// or when the accessibility bus (AT-SPI) can not be found.
 	#[cfg_attr(feature = "tracing", tracing::instrument)]
 	pub async fn new() -> zbus::Result<Self> {
+		let mut conn = Self::from_address(Self::lookup_bus_address().await?).await?;
+		conn.bus_address = None;
+		Ok(conn)
+	}
+
+	/// Asks the session bus for the address of the accessibility bus.
+	async fn lookup_bus_address() -> zbus::Result<Address> {
 		// Grab the a11y bus address from the session bus
 		let a11y_bus_addr = {
 			#[cfg(feature = "tracing")]
@@ -57,9 +128,7 @@ impl AccessibilityConnection {
 
 		#[cfg(feature = "tracing")]
 		tracing::debug!(address = %a11y_bus_addr, "Got a11y bus address");
-		let addr: Address = a11y_bus_addr.parse()?;
-
-		Self::from_address(addr).await
+		a11y_bus_addr.parse()
 	}
 
 	/// Returns an [`AccessibilityConnection`], a wrapper for the [`RegistryProxy`]; a handle for the registry provider
@@ -75,6 +144,20 @@ impl AccessibilityConnection {
 	///
 	/// `RegistryProxy` is configured with invalid path, interface or destination
 	pub async fn from_address(bus_addr: Address) -> zbus::Result<Self> {
+		let (registry, dbus_proxy) = Self::connect(bus_addr.clone()).await?;
+		Ok(Self {
+			registry,
+			dbus_proxy,
+			bus_address: Some(bus_addr),
+			match_rules: Mutex::default(),
+			registry_events: Mutex::default(),
+			method_timeout: None,
+		})
+	}
+
+	async fn connect(
+		bus_addr: Address,
+	) -> zbus::Result<(RegistryProxy<'static>, DBusProxy<'static>)> {
 		#[cfg(feature = "tracing")]
 		tracing::debug!("Connecting to a11y bus");
 		let bus = Box::pin(zbus::ConnectionBuilder::address(bus_addr)?.build()).await?;
@@ -86,7 +169,181 @@ impl AccessibilityConnection {
 		let registry = RegistryProxy::new(&bus).await?;
 		let dbus_proxy = DBusProxy::new(registry.inner().connection()).await?;
 
-		Ok(Self { registry, dbus_proxy })
+		Ok((registry, dbus_proxy))
+	}
+
+	/// Give up on method calls which the connection makes after `timeout`, failing them with
+	/// [`zbus::fdo::Error::TimedOut`], so a frozen application cannot block the caller indefinitely.
+	///
+	/// The timeout applies to the method calls made by:
+	///
+	/// - [`Self::add_registry_event`] and [`Self::remove_registry_event`],
+	///   and so [`Self::register_event`] and [`Self::deregister_event`];
+	/// - [`Self::add_match_rule`] and [`Self::remove_match_rule`];
+	/// - [`EventRegistration::apply`] and [`RegistrationGuard::release`];
+	/// - [`Self::reconnect`], for each match rule and registry event it adds again;
+	/// - [`Self::get_text_selection`] and [`Self::objects_with_state`].
+	///
+	/// It does not apply to opening the connection, or to sending events with [`Self::send_event`]
+	/// or [`Self::send_event_to`].
+	/// Calls on proxies built on [`Self::connection`], e.g. to an application, are only subject to it
+	/// when wrapped in [`Self::call_with_timeout`].
+	///
+	/// By default there is no timeout: a call waits until the application replies,
+	/// or the bus gives up on it.
+	///
+	/// With the `tokio` feature, the timeout is measured by Tokio's timer,
+	/// so these calls must run on a Tokio runtime with the time driver enabled
+	/// (see `tokio::runtime::Builder::enable_time`); otherwise they panic.
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::{AtspiError, ObjectRef};
+	/// use std::time::{Duration, Instant};
+	/// # use atspi_proxies::bus::BusProxy;
+	/// # struct FrozenDocument;
+	/// # #[zbus::interface(name = "org.a11y.atspi.Document")]
+	/// # impl FrozenDocument {
+	/// #     async fn get_text_selections(&self) -> Vec<(ObjectRef, i32, ObjectRef, i32, bool)> {
+	/// #         std::future::pending().await
+	/// #     }
+	/// # }
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new()
+	///     .await
+	///     .unwrap()
+	///     .with_method_timeout(Duration::from_millis(200));
+	/// # let session = zbus::Connection::session().await.unwrap();
+	/// # let address = BusProxy::new(&session).await.unwrap().get_address().await.unwrap();
+	/// # let path = "/org/a11y/atspi/accessible/document";
+	/// # let provider = zbus::connection::Builder::address(address.as_str()).unwrap()
+	/// #     .serve_at(path, FrozenDocument).unwrap()
+	/// #     .build().await.unwrap();
+	/// # let doc = ObjectRef {
+	/// #     name: provider.unique_name().unwrap().clone(),
+	/// #     path: path.try_into().unwrap(),
+	/// # };
+	/// // `doc` refers to a document in an application which never replies.
+	/// let start = Instant::now();
+	/// let result = atspi.get_text_selection(&doc).await;
+	/// assert!(matches!(result, Err(AtspiError::ZbusTimedOut(_))));
+	/// let elapsed = start.elapsed();
+	/// assert!(elapsed >= Duration::from_millis(200));
+	/// assert!(elapsed < Duration::from_secs(5));
+	/// # })
+	/// ```
+	#[must_use]
+	pub fn with_method_timeout(mut self, timeout: Duration) -> Self {
+		self.method_timeout = Some(timeout);
+		self
+	}
+
+	/// The timeout set by [`Self::with_method_timeout`], if any.
+	#[must_use]
+	pub fn method_timeout(&self) -> Option<Duration> {
+		self.method_timeout
+	}
+
+	/// Awaits `call`, failing it with [`zbus::fdo::Error::TimedOut`] if it takes longer than
+	/// the [`Self::method_timeout`].
+	///
+	/// This applies the timeout to method calls the connection does not make itself,
+	/// e.g. on proxies for an application built on [`Self::connection`]:
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use std::time::Duration;
+	/// use zbus::fdo::DBusProxy;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new()
+	///     .await
+	///     .unwrap()
+	///     .with_method_timeout(Duration::from_secs(1));
+	/// let dbus = DBusProxy::new(atspi.connection()).await.unwrap();
+	/// let id = atspi.call_with_timeout(dbus.get_id()).await.unwrap();
+	/// assert!(!id.is_empty());
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If `call` fails, or times out.
+	pub async fn call_with_timeout<T, E: From<zbus::fdo::Error>>(
+		&self,
+		call: impl Future<Output = Result<T, E>>,
+	) -> Result<T, E> {
+		let Some(timeout) = self.method_timeout else {
+			return call.await;
+		};
+		let expired = async {
+			#[cfg(feature = "tokio")]
+			tokio::time::sleep(timeout).await;
+			#[cfg(not(feature = "tokio"))]
+			async_io::Timer::after(timeout).await;
+			Err(zbus::fdo::Error::TimedOut(format!("method call timed out after {timeout:?}"))
+				.into())
+		};
+		futures_lite::future::or(call, expired).await
+	}
+
+	/// Re-open the connection to the accessibility bus, e.g. after the bus restarted.
+	///
+	/// All match rules and registry events added through this connection, and not removed since,
+	/// are added to the new connection again.
+	/// Streams obtained before reconnecting end with the old connection;
+	/// call [`Self::event_stream`] again to receive events from the new one.
+	///
+	/// If the connection was opened with [`Self::new`], the address of the accessibility bus is looked up again,
+	/// as a restarted bus usually listens on a new address.
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::events::{
+	///     object::StateChangedEvent, window::ActivateEvent, Event, ObjectEvents, WindowEvents,
+	/// };
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let mut atspi = AccessibilityConnection::new().await.unwrap();
+	/// atspi.register_event::<ObjectEvents>().await.unwrap();
+	/// // Simulate the bus going away.
+	/// atspi.connection().clone().close().await.unwrap();
+	///
+	/// atspi.reconnect().await.unwrap();
+	/// atspi.register_event::<WindowEvents>().await.unwrap();
+	/// let events = atspi.event_stream();
+	/// let mut events = std::pin::pin!(events);
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	/// atspi.send_event(ActivateEvent::default()).await.unwrap();
+	///
+	/// let event = events.next().await.unwrap().unwrap();
+	/// assert!(matches!(event, Event::Object(ObjectEvents::StateChanged(_))));
+	/// let event = events.next().await.unwrap().unwrap();
+	/// assert!(matches!(event, Event::Window(WindowEvents::Activate(_))));
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If the bus cannot be reached, or any of the match rules or registry events cannot be added again.
+	/// The connection is left unchanged in that case, so reconnecting may be retried.
+	pub async fn reconnect(&mut self) -> Result<(), AtspiError> {
+		let bus_addr = match &self.bus_address {
+			Some(addr) => addr.clone(),
+			None => Self::lookup_bus_address().await?,
+		};
+		let (registry, dbus_proxy) = Self::connect(bus_addr).await?;
+		let registry_events = lock(&self.registry_events).clone();
+		let match_rules = lock(&self.match_rules).clone();
+		for event in registry_events {
+			self.call_with_timeout(registry.register_event(event)).await?;
+		}
+		for rule in match_rules {
+			self.call_with_timeout(dbus_proxy.add_match_rule(MatchRule::try_from(rule)?))
+				.await?;
+		}
+		self.registry = registry;
+		self.dbus_proxy = dbus_proxy;
+		Ok(())
 	}
 
 	/// Stream yielding all `Event` types.
@@ -172,6 +429,93 @@ impl AccessibilityConnection {
 		})
 	}
 
+	/// Stream yielding only the `Event`s whose `DBus` member is in `members`, e.g. `"StateChanged"`.
+	///
+	/// Messages are filtered by member before they are converted to events,
+	/// so events which would be discarded anyway are never built.
+	/// An empty slice of members lets all events through, like [`Self::event_stream`].
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::events::{
+	///     object::{BoundsChangedEvent, StateChangedEvent},
+	///     Event, ObjectEvents,
+	/// };
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// atspi.register_event::<ObjectEvents>().await.unwrap();
+	///
+	/// let events = atspi.event_stream_filtered(&["BoundsChanged"]);
+	/// let mut events = std::pin::pin!(events);
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	/// atspi.send_event(BoundsChangedEvent::default()).await.unwrap();
+	///
+	/// let event = events.next().await.unwrap().unwrap();
+	/// assert!(matches!(event, Event::Object(ObjectEvents::BoundsChanged(_))));
+	/// # })
+	/// ```
+	pub fn event_stream_filtered(
+		&self,
+		members: &[&str],
+	) -> impl Stream<Item = Result<Event, AtspiError>> {
+		let members: Vec<String> = members.iter().map(ToString::to_string).collect();
+		MessageStream::from(self.registry.inner().connection()).filter_map(move |res| {
+			let msg = match res {
+				Ok(m) => m,
+				Err(e) => return Some(Err(e.into())),
+			};
+			if msg.message_type() != MessageType::Signal {
+				return None;
+			}
+			if !members.is_empty() {
+				let header = msg.header();
+				let member = header.member()?;
+				if !members.iter().any(|allowed| allowed == member.as_str()) {
+					return None;
+				}
+			}
+			Some(Event::try_from(&msg))
+		})
+	}
+
+	/// Stream yielding all `Event` types, each stamped with the time it was received.
+	///
+	/// See [`TimestampedEvent`] for the semantics of the timestamps.
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::events::{object::StateChangedEvent, ObjectEvents};
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// atspi.register_event::<ObjectEvents>().await.unwrap();
+	///
+	/// let events = atspi.timestamped_event_stream();
+	/// let mut events = std::pin::pin!(events);
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	///
+	/// let first = events.next().await.unwrap().unwrap();
+	/// let second = events.next().await.unwrap().unwrap();
+	/// assert!(first.timestamp() <= second.timestamp());
+	/// # })
+	/// ```
+	pub fn timestamped_event_stream(
+		&self,
+	) -> impl Stream<Item = Result<TimestampedEvent, AtspiError>> {
+		MessageStream::from(self.registry.inner().connection()).filter_map(|res| {
+			let msg = match res {
+				Ok(m) => m,
+				Err(e) => return Some(Err(e.into())),
+			};
+			match msg.message_type() {
+				MessageType::Signal => Some(TimestampedEvent::try_from(&msg)),
+				_ => None,
+			}
+		})
+	}
+
 	/// Registers an events as defined in [`atspi-types::events`]. This function registers a single event, like so:
 	/// ```rust
 	/// use atspi_connection::common::events::object::StateChangedEvent;
@@ -181,12 +525,21 @@ impl AccessibilityConnection {
 	/// # })
 	/// ```
 	///
+	/// Like the bus, the connection counts how often a match rule was added:
+	/// it stays active until it has been removed as many times.
+	///
 	/// # Errors
 	///
 	/// This function may return an error if a [`zbus::Error`] is caused by all the various calls to [`zbus::fdo::DBusProxy`] and [`zbus::MatchRule::try_from`].
 	pub async fn add_match_rule<T: HasMatchRule>(&self) -> Result<(), AtspiError> {
-		let match_rule = MatchRule::try_from(<T as HasMatchRule>::MATCH_RULE_STRING)?;
-		self.dbus_proxy.add_match_rule(match_rule).await?;
+		self.add_match_rule_str(<T as HasMatchRule>::MATCH_RULE_STRING).await
+	}
+
+	/// Like [`Self::add_match_rule`], for the match rule string of an event type.
+	pub(crate) async fn add_match_rule_str(&self, rule: &'static str) -> Result<(), AtspiError> {
+		self.call_with_timeout(self.dbus_proxy.add_match_rule(MatchRule::try_from(rule)?))
+			.await?;
+		lock(&self.match_rules).push(rule);
 		Ok(())
 	}
 
@@ -200,12 +553,29 @@ impl AccessibilityConnection {
 	/// # })
 	/// ```
 	///
+	/// Removing a match rule which is not active, e.g. because it was removed already, is a no-op.
+	///
 	/// # Errors
 	///
 	/// This function may return an error if a [`zbus::Error`] is caused by all the various calls to [`zbus::fdo::DBusProxy`] and [`zbus::MatchRule::try_from`].
 	pub async fn remove_match_rule<T: HasMatchRule>(&self) -> Result<(), AtspiError> {
-		let match_rule = MatchRule::try_from(<T as HasMatchRule>::MATCH_RULE_STRING)?;
-		self.dbus_proxy.add_match_rule(match_rule).await?;
+		self.remove_match_rule_str(<T as HasMatchRule>::MATCH_RULE_STRING)
+			.await
+	}
+
+	/// Like [`Self::remove_match_rule`], for the match rule string of an event type.
+	pub(crate) async fn remove_match_rule_str(&self, rule: &'static str) -> Result<(), AtspiError> {
+		let match_rule = MatchRule::try_from(rule)?;
+		if !remove_one(&self.match_rules, rule) {
+			return Ok(());
+		}
+		if let Err(e) = self
+			.call_with_timeout(self.dbus_proxy.remove_match_rule(match_rule))
+			.await
+		{
+			lock(&self.match_rules).push(rule);
+			return Err(e.into());
+		}
 		Ok(())
 	}
 
@@ -226,9 +596,17 @@ impl AccessibilityConnection {
 	///
 	/// May cause an error if the `DBus` method [`atspi_proxies::registry::RegistryProxy::register_event`] fails.
 	pub async fn add_registry_event<T: HasRegistryEventString>(&self) -> Result<(), AtspiError> {
-		self.registry
-			.register_event(<T as HasRegistryEventString>::REGISTRY_EVENT_STRING)
-			.await?;
+		self.add_registry_event_str(<T as HasRegistryEventString>::REGISTRY_EVENT_STRING)
+			.await
+	}
+
+	/// Like [`Self::add_registry_event`], for the registry event string of an event type.
+	pub(crate) async fn add_registry_event_str(
+		&self,
+		event: &'static str,
+	) -> Result<(), AtspiError> {
+		self.call_with_timeout(self.registry.register_event(event)).await?;
+		lock(&self.registry_events).push(event);
 		Ok(())
 	}
 
@@ -250,13 +628,48 @@ impl AccessibilityConnection {
 	///
 	/// May cause an error if the `DBus` method [`RegistryProxy::deregister_event`] fails.
 	pub async fn remove_registry_event<T: HasRegistryEventString>(&self) -> Result<(), AtspiError> {
-		self.registry
-			.deregister_event(<T as HasRegistryEventString>::REGISTRY_EVENT_STRING)
-			.await?;
+		self.remove_registry_event_str(<T as HasRegistryEventString>::REGISTRY_EVENT_STRING)
+			.await
+	}
+
+	/// Like [`Self::remove_registry_event`], for the registry event string of an event type.
+	pub(crate) async fn remove_registry_event_str(
+		&self,
+		event: &'static str,
+	) -> Result<(), AtspiError> {
+		self.call_with_timeout(self.registry.deregister_event(event)).await?;
+		remove_one(&self.registry_events, event);
 		Ok(())
 	}
 
 	/// This calls [`Self::add_registry_event`] and [`Self::add_match_rule`], two components necessary to receive accessibility events.
+	///
+	/// An event registered twice stays registered until it has been deregistered twice:
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::events::{
+	///     object::StateChangedEvent, window::ActivateEvent, Event, ObjectEvents, WindowEvents,
+	/// };
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// atspi.register_event::<StateChangedEvent>().await.unwrap();
+	/// atspi.register_event::<StateChangedEvent>().await.unwrap();
+	/// atspi.register_event::<WindowEvents>().await.unwrap();
+	/// atspi.deregister_event::<StateChangedEvent>().await.unwrap();
+	///
+	/// let events = atspi.event_stream();
+	/// let mut events = std::pin::pin!(events);
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	/// atspi.send_event(ActivateEvent::default()).await.unwrap();
+	///
+	/// // The state change is still received, ahead of the window event.
+	/// let event = events.next().await.unwrap().unwrap();
+	/// assert!(matches!(event, Event::Object(ObjectEvents::StateChanged(_))));
+	/// # })
+	/// ```
+	///
 	/// # Errors
 	/// This will only fail if [`Self::add_registry_event`[ or [`Self::add_match_rule`] fails.
 	pub async fn register_event<T: HasRegistryEventString + HasMatchRule>(
@@ -268,6 +681,34 @@ impl AccessibilityConnection {
 	}
 
 	/// This calls [`Self::remove_registry_event`] and [`Self::remove_match_rule`], two components necessary to receive accessibility events.
+	///
+	/// Once deregistered, events of type `T` no longer arrive on the connection's streams:
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::events::{
+	///     object::StateChangedEvent, window::ActivateEvent, Event, ObjectEvents, WindowEvents,
+	/// };
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// atspi.register_event::<ObjectEvents>().await.unwrap();
+	/// atspi.register_event::<WindowEvents>().await.unwrap();
+	/// atspi.deregister_event::<ObjectEvents>().await.unwrap();
+	/// // Deregistering twice does no harm.
+	/// atspi.deregister_event::<ObjectEvents>().await.unwrap();
+	///
+	/// let events = atspi.event_stream();
+	/// let mut events = std::pin::pin!(events);
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	/// atspi.send_event(ActivateEvent::default()).await.unwrap();
+	///
+	/// // The object event was sent first, but is no longer received.
+	/// let event = events.next().await.unwrap().unwrap();
+	/// assert!(matches!(event, Event::Window(WindowEvents::Activate(_))));
+	/// # })
+	/// ```
+	///
 	/// # Errors
 	/// This will only fail if [`Self::remove_registry_event`] or [`Self::remove_match_rule`] fails.
 	pub async fn deregister_event<T: HasRegistryEventString + HasMatchRule>(
@@ -278,6 +719,130 @@ impl AccessibilityConnection {
 		Ok(())
 	}
 
+	/// Get the primary text selection of a document, which may span multiple objects.
+	///
+	/// `doc` must implement the `Document` interface.
+	/// Returns `None` if nothing is selected.
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::ObjectRef;
+	/// # use atspi_proxies::bus::BusProxy;
+	/// #
+	/// # struct MockDocument;
+	/// #
+	/// # #[zbus::interface(name = "org.a11y.atspi.Document")]
+	/// # impl MockDocument {
+	/// #     fn get_text_selections(&self) -> Vec<(ObjectRef, i32, ObjectRef, i32, bool)> {
+	/// #         let paragraph = |n: u32| ObjectRef {
+	/// #             name: ":1.42".try_into().unwrap(),
+	/// #             path: format!("/org/a11y/atspi/accessible/{n}").try_into().unwrap(),
+	/// #         };
+	/// #         vec![(paragraph(1), 5, paragraph(2), 3, true), (paragraph(3), 0, paragraph(3), 4, false)]
+	/// #     }
+	/// # }
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// # let session = zbus::Connection::session().await.unwrap();
+	/// # let address = BusProxy::new(&session).await.unwrap().get_address().await.unwrap();
+	/// # let path = "/org/a11y/atspi/accessible/document";
+	/// # let provider = zbus::connection::Builder::address(address.as_str()).unwrap()
+	/// #     .serve_at(path, MockDocument).unwrap()
+	/// #     .build().await.unwrap();
+	/// # let doc = ObjectRef {
+	/// #     name: provider.unique_name().unwrap().clone(),
+	/// #     path: path.try_into().unwrap(),
+	/// # };
+	/// // `doc` refers to a document in which the selection starts in one paragraph and ends in the next.
+	/// let selection = atspi.get_text_selection(&doc).await.unwrap().unwrap();
+	/// assert_eq!(selection.start_obj().path.as_str(), "/org/a11y/atspi/accessible/1");
+	/// assert_eq!(selection.start_idx(), 5);
+	/// assert_eq!(selection.end_obj().path.as_str(), "/org/a11y/atspi/accessible/2");
+	/// assert_eq!(selection.end_idx(), 3);
+	/// assert!(selection.start_is_active());
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If the `DocumentProxy` cannot be built, or the `GetTextSelections` call fails.
+	pub async fn get_text_selection(
+		&self,
+		doc: &ObjectRef,
+	) -> Result<Option<TextSelection>, AtspiError> {
+		let document = doc.clone().into_document(self.connection()).await?;
+		Ok(self
+			.call_with_timeout(document.get_text_selections())
+			.await?
+			.into_iter()
+			.next())
+	}
+
+	/// Find the objects below `root` which have `state`, e.g. all [`State::Focused`] objects of an application.
+	///
+	/// The objects are returned in canonical order, and may include `root` itself.
+	///
+	/// `root` must implement the `Collection` interface, which is usually only true of
+	/// an application's root object and of documents.
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::{ObjectMatchRule, ObjectRef, State, StateSet};
+	/// # use atspi_proxies::bus::BusProxy;
+	/// # struct MockCollection {
+	/// #     objects: Vec<(ObjectRef, StateSet)>,
+	/// # }
+	/// # #[zbus::interface(name = "org.a11y.atspi.Collection")]
+	/// # impl MockCollection {
+	/// #     fn get_matches(&self, rule: ObjectMatchRule, _sortby: u32, _count: i32, _traverse: bool) -> Vec<ObjectRef> {
+	/// #         self.objects.iter().filter(|(_, states)| *states & rule.states == rule.states).map(|(obj, _)| obj.clone()).collect()
+	/// #     }
+	/// # }
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// # let session = zbus::Connection::session().await.unwrap();
+	/// # let address = BusProxy::new(&session).await.unwrap().get_address().await.unwrap();
+	/// # let obj = |n: u32| ObjectRef {
+	/// #     name: ":1.42".try_into().unwrap(),
+	/// #     path: format!("/org/a11y/atspi/accessible/{n}").try_into().unwrap(),
+	/// # };
+	/// # let objects = vec![
+	/// #     (obj(1), StateSet::new(State::Focusable | State::Focused)),
+	/// #     (obj(2), StateSet::new(State::Focusable)),
+	/// # ];
+	/// # let path = "/org/a11y/atspi/accessible/root";
+	/// # let provider = zbus::connection::Builder::address(address.as_str()).unwrap()
+	/// #     .serve_at(path, MockCollection { objects }).unwrap()
+	/// #     .build().await.unwrap();
+	/// # let app = ObjectRef {
+	/// #     name: provider.unique_name().unwrap().clone(),
+	/// #     path: path.try_into().unwrap(),
+	/// # };
+	/// // `app` refers to the root of an application with two focusable objects, one of them focused.
+	/// let focused = atspi.objects_with_state(&app, State::Focused).await.unwrap();
+	/// assert_eq!(focused.len(), 1);
+	/// assert_eq!(focused[0].path.as_str(), "/org/a11y/atspi/accessible/1");
+	/// assert_eq!(atspi.objects_with_state(&app, State::Focusable).await.unwrap().len(), 2);
+	/// assert!(atspi.objects_with_state(&app, State::Checked).await.unwrap().is_empty());
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If the `CollectionProxy` cannot be built, or the `GetMatches` call fails,
+	/// e.g. because `root` does not implement `Collection`.
+	pub async fn objects_with_state(
+		&self,
+		root: &ObjectRef,
+		state: State,
+	) -> Result<Vec<ObjectRef>, AtspiError> {
+		let collection = root.clone().into_collection(self.connection()).await?;
+		let rule = ObjectMatchRule::builder().states([state], MatchType::All).build();
+		Ok(self
+			.call_with_timeout(collection.get_matches(rule, SortOrder::Canonical, 0, true))
+			.await?)
+	}
+
 	/// Shorthand for a reference to the underlying [`zbus::Connection`]
 	#[must_use = "The reference to the underlying zbus::Connection must be used"]
 	pub fn connection(&self) -> &zbus::Connection {
@@ -295,19 +860,78 @@ impl AccessibilityConnection {
 	///
 	/// Both of these conditions should never happen as long as you have a valid event.
 	pub async fn send_event<T>(&self, event: T) -> Result<(), AtspiError>
+	where
+		T: BusProperties + EventProperties + MessageConversion,
+	{
+		let new_message = self.event_message(&event, None)?;
+		Ok(self.connection().send(&new_message).await?)
+	}
+
+	/// Send an event to a single connection on the accessibility bus, rather than broadcasting it.
+	///
+	/// This is useful in tests, or to forward an event to one particular client.
+	/// Returns the serial number of the sent message.
+	///
+	/// ```rust
+	/// use atspi_connection::AccessibilityConnection;
+	/// use atspi_connection::common::events::object::StateChangedEvent;
+	/// use zbus::MessageStream;
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// let client = AccessibilityConnection::new().await.unwrap();
+	/// let client_name = client.connection().unique_name().unwrap().to_string();
+	/// let mut messages = MessageStream::from(client.connection());
+	///
+	/// let serial = atspi.send_event_to(StateChangedEvent::default(), &client_name).await.unwrap();
+	/// let msg = loop {
+	///     let msg = messages.next().await.unwrap().unwrap();
+	///     if msg.header().member().is_some_and(|member| member == "StateChanged") {
+	///         break msg;
+	///     }
+	/// };
+	/// assert_eq!(msg.header().destination().unwrap().as_str(), client_name);
+	/// assert_eq!(msg.primary_header().serial_num().get(), serial);
+	///
+	/// assert!(atspi.send_event_to(StateChangedEvent::default(), "not a bus name").await.is_err());
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// - If `destination` is not a valid bus name.
+	/// - If the [`zbus::Message`] cannot be built, or sending it fails.
+	pub async fn send_event_to<T>(&self, event: T, destination: &str) -> Result<u32, AtspiError>
+	where
+		T: BusProperties + EventProperties + MessageConversion,
+	{
+		let destination = zbus::names::BusName::try_from(destination)?;
+		let new_message = self.event_message(&event, Some(destination))?;
+		self.connection().send(&new_message).await?;
+		Ok(new_message.primary_header().serial_num().get())
+	}
+
+	/// Build the signal carrying `event`, sent from this connection.
+	fn event_message<T>(
+		&self,
+		event: &T,
+		destination: Option<zbus::names::BusName<'_>>,
+	) -> Result<zbus::Message, AtspiError>
 	where
 		T: BusProperties + EventProperties + MessageConversion,
 	{
 		let conn = self.connection();
-		let new_message = zbus::Message::signal(
+		let mut builder = zbus::Message::signal(
 			event.path(),
 			<T as BusProperties>::DBUS_INTERFACE,
 			<T as BusProperties>::DBUS_MEMBER,
 		)?
-		.sender(conn.unique_name().ok_or(AtspiError::MissingName)?)?
+		.sender(conn.unique_name().ok_or(AtspiError::MissingName)?)?;
+		if let Some(destination) = destination {
+			builder = builder.destination(destination)?;
+		}
 		// this re-encodes the entire body; it's not great..., but you can't replace a sender once a message a created.
-		.build(&event.body())?;
-		Ok(conn.send(&new_message).await?)
+		Ok(builder.build(&event.body())?)
 	}
 }
 
@@ -319,6 +943,54 @@ impl Deref for AccessibilityConnection {
 	}
 }
 
+/// Resolve the socket carried by an [`AvailableEvent`].
+pub trait AvailableEventExt {
+	/// Returns an [`AccessibleProxy`] for the registry's root accessible, as announced by the `Socket:Available` signal.
+	///
+	/// The registry root is the entry point into the accessibility tree: its children are the applications on the bus.
+	/// This is a good moment to (re)seed a cache.
+	///
+	/// ```rust
+	/// use atspi_connection::{AccessibilityConnection, AvailableEventExt};
+	/// use atspi_connection::common::{events::AvailableEvent, ObjectRef, Role};
+	/// use zbus::{fdo::DBusProxy, names::BusName};
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// let dbus = DBusProxy::new(atspi.connection()).await.unwrap();
+	/// let registry = BusName::try_from("org.a11y.atspi.Registry").unwrap();
+	/// let owner = dbus.get_name_owner(registry).await.unwrap();
+	///
+	/// let event = AvailableEvent {
+	///     item: ObjectRef::default(),
+	///     socket: ObjectRef {
+	///         name: owner,
+	///         path: "/org/a11y/atspi/accessible/root".try_into().unwrap(),
+	///     },
+	/// };
+	/// let root = event.registry_root(atspi.connection()).await.unwrap();
+	/// assert_eq!(root.inner().path().as_str(), "/org/a11y/atspi/accessible/root");
+	/// assert_eq!(root.get_role().await.unwrap(), Role::DesktopFrame);
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built from the socket's name and path. See [`zbus::ProxyBuilder::build`].
+	fn registry_root(
+		&self,
+		conn: &zbus::Connection,
+	) -> impl std::future::Future<Output = Result<AccessibleProxy<'static>, AtspiError>> + Send;
+}
+
+impl AvailableEventExt for AvailableEvent {
+	async fn registry_root(
+		&self,
+		conn: &zbus::Connection,
+	) -> Result<AccessibleProxy<'static>, AtspiError> {
+		Ok(self.socket().clone().into_accessible(conn).await?)
+	}
+}
+
 /// Set the `IsEnabled` property in the session bus.
 ///
 /// Assistive Technology provider applications (ATs) should set the accessibility
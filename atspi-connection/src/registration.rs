@@ -0,0 +1,210 @@
+//! Registering a set of event types at once, for as long as a guard is held.
+
+use crate::common::events::{HasMatchRule, HasRegistryEventString};
+use crate::{AccessibilityConnection, AtspiResult};
+
+/// A set of event types to register with the accessibility bus in one go.
+///
+/// Event types are added with [`EventRegistration::with`], which only accepts types that can be registered.
+/// Overlapping registrations are removed: adding [`ObjectEvents`] makes any member-level object event,
+/// like [`StateChangedEvent`], redundant, regardless of the order in which they are added.
+///
+/// ```rust
+/// use atspi_connection::EventRegistration;
+/// use atspi_connection::common::events::{
+///     object::{ObjectEvents, StateChangedEvent},
+///     window::ActivateEvent,
+/// };
+///
+/// let registration = EventRegistration::new()
+///     .with::<StateChangedEvent>()
+///     .with::<ActivateEvent>()
+///     .with::<ObjectEvents>();
+///
+/// assert_eq!(
+///     registration.match_rules(),
+///     [
+///         "type='signal',interface='org.a11y.atspi.Event.Window',member='Activate'",
+///         "type='signal',interface='org.a11y.atspi.Event.Object'",
+///     ]
+/// );
+/// assert_eq!(registration.registry_events(), ["Object:", "Window:"]);
+/// ```
+///
+/// [`ObjectEvents`]: crate::common::events::ObjectEvents
+/// [`StateChangedEvent`]: crate::common::events::object::StateChangedEvent
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventRegistration {
+	match_rules: Vec<&'static str>,
+	registry_events: Vec<&'static str>,
+}
+
+/// Whether registering `broad` makes registering `narrow` redundant.
+fn covers(broad: &str, narrow: &str, separator: char) -> bool {
+	narrow.strip_prefix(broad).is_some_and(|rest| {
+		rest.is_empty() || rest.starts_with(separator) || broad.ends_with(separator)
+	})
+}
+
+/// Add `item` to `set`, unless it is covered already, removing the items it covers.
+fn insert_covering(set: &mut Vec<&'static str>, item: &'static str, separator: char) {
+	if set.iter().any(|existing| covers(existing, item, separator)) {
+		return;
+	}
+	set.retain(|existing| !covers(item, existing, separator));
+	set.push(item);
+}
+
+impl EventRegistration {
+	/// An empty set of event registrations.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add the event type `T` to the set.
+	#[must_use]
+	pub fn with<T: HasRegistryEventString + HasMatchRule>(mut self) -> Self {
+		insert_covering(&mut self.match_rules, <T as HasMatchRule>::MATCH_RULE_STRING, ',');
+		insert_covering(
+			&mut self.registry_events,
+			<T as HasRegistryEventString>::REGISTRY_EVENT_STRING,
+			':',
+		);
+		self
+	}
+
+	/// The match rules which will be added to the connection.
+	#[must_use]
+	pub fn match_rules(&self) -> &[&'static str] {
+		&self.match_rules
+	}
+
+	/// The event strings which will be registered with the registry.
+	#[must_use]
+	pub fn registry_events(&self) -> &[&'static str] {
+		&self.registry_events
+	}
+
+	/// Register all events in the set.
+	///
+	/// The events are registered through [`AccessibilityConnection::register_event`]'s machinery,
+	/// so they are subject to the connection's method timeout, re-registered by
+	/// [`AccessibilityConnection::reconnect`], and counted together with the events registered otherwise.
+	/// They stay registered until the returned [`RegistrationGuard`] is released.
+	///
+	/// ```rust
+	/// use atspi_connection::{AccessibilityConnection, EventRegistration};
+	/// use atspi_connection::common::events::object::StateChangedEvent;
+	/// # tokio_test::block_on(async {
+	/// let connection = AccessibilityConnection::new().await.unwrap();
+	/// let guard = EventRegistration::new()
+	///     .with::<StateChangedEvent>()
+	///     .apply(&connection)
+	///     .await
+	///     .unwrap();
+	/// // Receive events from `connection.event_stream()` here.
+	/// guard.release(&connection).await.unwrap();
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If any of the match rules or registry events cannot be added.
+	/// Whatever was registered before the failure is deregistered again.
+	pub async fn apply(self, conn: &AccessibilityConnection) -> AtspiResult<RegistrationGuard> {
+		let mut guard = RegistrationGuard {
+			match_rules: Vec::with_capacity(self.match_rules.len()),
+			registry_events: Vec::with_capacity(self.registry_events.len()),
+		};
+		for event in self.registry_events {
+			if let Err(e) = conn.add_registry_event_str(event).await {
+				let _ = guard.release(conn).await;
+				return Err(e);
+			}
+			guard.registry_events.push(event);
+		}
+		for rule in self.match_rules {
+			if let Err(e) = conn.add_match_rule_str(rule).await {
+				let _ = guard.release(conn).await;
+				return Err(e);
+			}
+			guard.match_rules.push(rule);
+		}
+		Ok(guard)
+	}
+}
+
+/// Keeps the events of an [`EventRegistration`] registered.
+///
+/// The events are deregistered by [`RegistrationGuard::release`], which must be passed the connection
+/// the guard was obtained from. Dropping the guard leaves the events registered.
+#[must_use = "the events stay registered until the guard is released"]
+#[derive(Debug)]
+pub struct RegistrationGuard {
+	match_rules: Vec<&'static str>,
+	registry_events: Vec<&'static str>,
+}
+
+impl RegistrationGuard {
+	/// The match rules currently added to the connection by this guard.
+	#[must_use]
+	pub fn match_rules(&self) -> &[&'static str] {
+		&self.match_rules
+	}
+
+	/// The event strings currently registered with the registry by this guard.
+	#[must_use]
+	pub fn registry_events(&self) -> &[&'static str] {
+		&self.registry_events
+	}
+
+	/// Deregister all events held by this guard from `conn`, the connection passed to [`EventRegistration::apply`].
+	///
+	/// Only this guard's registrations are removed: an event type which was also registered separately,
+	/// e.g. with [`AccessibilityConnection::register_event`], stays registered.
+	///
+	/// ```rust
+	/// use atspi_connection::{AccessibilityConnection, EventRegistration};
+	/// use atspi_connection::common::events::{
+	///     object::StateChangedEvent, window::ActivateEvent, Event, ObjectEvents, WindowEvents,
+	/// };
+	/// use futures_lite::StreamExt;
+	/// # tokio_test::block_on(async {
+	/// let atspi = AccessibilityConnection::new().await.unwrap();
+	/// atspi.register_event::<ObjectEvents>().await.unwrap();
+	/// let guard = EventRegistration::new()
+	///     .with::<ObjectEvents>()
+	///     .with::<WindowEvents>()
+	///     .apply(&atspi)
+	///     .await
+	///     .unwrap();
+	/// guard.release(&atspi).await.unwrap();
+	/// atspi.register_event::<WindowEvents>().await.unwrap();
+	///
+	/// let events = atspi.event_stream();
+	/// let mut events = std::pin::pin!(events);
+	/// atspi.send_event(StateChangedEvent::default()).await.unwrap();
+	/// atspi.send_event(ActivateEvent::default()).await.unwrap();
+	///
+	/// // The separate registration of object events outlives the guard.
+	/// let event = events.next().await.unwrap().unwrap();
+	/// assert!(matches!(event, Event::Object(ObjectEvents::StateChanged(_))));
+	/// # })
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If any of the match rules or registry events cannot be removed.
+	/// Deregistration of the remaining events is still attempted.
+	pub async fn release(self, conn: &AccessibilityConnection) -> AtspiResult<()> {
+		let mut result = Ok(());
+		for rule in self.match_rules {
+			result = result.and(conn.remove_match_rule_str(rule).await);
+		}
+		for event in self.registry_events {
+			result = result.and(conn.remove_registry_event_str(event).await);
+		}
+		result
+	}
+}
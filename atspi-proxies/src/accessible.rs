@@ -6,8 +6,12 @@
 //! Accessible is the interface which is implemented by all accessible objects.
 //!
 
-use crate::common::{InterfaceSet, ObjectRef, RelationType, Role, StateSet};
+use crate::application::ApplicationProxy;
+use crate::common::{Interface, InterfaceSet, ObjectRef, RelationType, Role, StateSet};
+use crate::proxy_ext::{build_proxy, ObjectRefProxyExt};
 use crate::AtspiError;
+use futures_lite::stream::{self, Stream};
+use std::collections::HashSet;
 
 /// # `AccessibleProxy`
 ///
@@ -83,7 +87,9 @@ trait Accessible {
 	fn get_interfaces(&self) -> zbus::Result<InterfaceSet>;
 
 	/// Gets a `String` corresponding to the name of the role played by an object,
-	/// translated to the current locale.
+	/// translated to the current locale, for presenting to users.
+	///
+	/// For example, a [`Role::Button`] is described as "bouton" by an application running in French.
 	///
 	/// ## Notes
 	///
@@ -284,24 +290,201 @@ impl ObjectRefExt for ObjectRef {
 		&self,
 		conn: &zbus::Connection,
 	) -> Result<AccessibleProxy<'_>, zbus::Error> {
-		AccessibleProxy::builder(conn)
-			.destination(self.name.clone())?
-			.path(self.path.clone())?
-			.cache_properties(zbus::proxy::CacheProperties::No)
-			.build()
-			.await
+		build_proxy(self.clone(), conn).await
 	}
 
 	async fn into_accessible_proxy(
 		self,
 		conn: &zbus::Connection,
 	) -> Result<AccessibleProxy<'_>, zbus::Error> {
-		AccessibleProxy::builder(conn)
-			.destination(self.name)?
-			.path(self.path)?
-			.cache_properties(zbus::proxy::CacheProperties::No)
-			.build()
-			.await
+		build_proxy(self, conn).await
+	}
+}
+
+impl AccessibleProxy<'_> {
+	/// Returns the same relationships as [`AccessibleProxy::get_relation_set`],
+	/// but fails with a descriptive error when the application reports a relation type this crate does not know.
+	///
+	/// # Errors
+	///
+	/// - If the `DBus` call fails, or its reply cannot be decoded.
+	/// - [`AtspiError::UnknownRelation`] if a relation type is not a known [`RelationType`].
+	pub async fn get_relation_set_typed(
+		&self,
+	) -> Result<Vec<(RelationType, Vec<ObjectRef>)>, AtspiError> {
+		let reply = self.inner().call_method("GetRelationSet", &()).await?;
+		let relations: Vec<(u32, Vec<ObjectRef>)> = reply.body().deserialize()?;
+		relations
+			.into_iter()
+			.map(|(relation, targets)| Ok((RelationType::try_from(relation)?, targets)))
+			.collect()
+	}
+
+	/// The application-specific identifier of the object, e.g. for tests to find it by.
+	///
+	/// Like [`accessible_id`], but returns an empty string if the application predates the
+	/// `AccessibleId` property, as it does for an object without an id.
+	///
+	/// # Errors
+	///
+	/// If reading the property fails for any other reason.
+	///
+	/// [`accessible_id`]: #method.accessible_id
+	pub async fn accessible_id_or_empty(&self) -> zbus::Result<String> {
+		match self.accessible_id().await {
+			Err(zbus::Error::FDO(e))
+				if matches!(
+					*e,
+					zbus::fdo::Error::UnknownProperty(_) | zbus::fdo::Error::InvalidArgs(_)
+				) =>
+			{
+				Ok(String::new())
+			}
+			result => result,
+		}
+	}
+
+	/// The nesting level of the object, e.g. of a heading or a tree item, from its `level` attribute.
+	///
+	/// Returns `None` if the object has no `level` attribute, or its value is not a number.
+	///
+	/// # Errors
+	///
+	/// If the [`get_attributes`] call fails.
+	///
+	/// [`get_attributes`]: #method.get_attributes
+	pub async fn level(&self) -> zbus::Result<Option<u32>> {
+		Ok(self
+			.get_attributes()
+			.await?
+			.get("level")
+			.and_then(|level| level.trim().parse().ok()))
+	}
+
+	/// Returns a proxy for each of the object's children, in order.
+	///
+	/// Null references to children, see [`ObjectRef::is_null`], are skipped.
+	/// The proxies share this proxy's connection, and do not cache properties.
+	///
+	/// # Errors
+	///
+	/// If the [`get_children`] call fails, or a proxy cannot be built.
+	///
+	/// [`get_children`]: #method.get_children
+	pub async fn children(&self) -> zbus::Result<Vec<AccessibleProxy<'static>>> {
+		let conn = self.inner().connection();
+		let mut children = Vec::new();
+		for child in self
+			.get_children()
+			.await?
+			.into_iter()
+			.filter(|child| !child.is_null())
+		{
+			children.push(child.into_accessible(conn).await?);
+		}
+		Ok(children)
+	}
+
+	/// Returns a proxy for the child at `index`, counting from 0.
+	///
+	/// The proxy shares this proxy's connection, and does not cache properties.
+	///
+	/// # Errors
+	///
+	/// - If the [`get_child_at_index`] call fails, or the proxy cannot be built.
+	/// - If the child reference is null, see [`ObjectRef::is_null`],
+	///   which is how applications usually report an index out of range.
+	///
+	/// [`get_child_at_index`]: #method.get_child_at_index
+	pub async fn child_proxy_at(&self, index: i32) -> zbus::Result<AccessibleProxy<'static>> {
+		let child = self.get_child_at_index(index).await?;
+		if child.is_null() {
+			return Err(zbus::Error::Failure(format!("object has no child at index {index}")));
+		}
+		child.into_accessible(self.inner().connection()).await
+	}
+
+	/// Returns a proxy for the object's parent, or `None` if the object is a root,
+	/// i.e. its parent is null, see [`ObjectRef::is_null`].
+	///
+	/// Together with [`get_index_in_parent`], this locates the object within its parent.
+	/// The proxy shares this proxy's connection, and does not cache properties.
+	///
+	/// # Errors
+	///
+	/// If reading the [`parent`] property fails, or the proxy cannot be built.
+	///
+	/// [`get_index_in_parent`]: #method.get_index_in_parent
+	/// [`parent`]: #method.parent
+	pub async fn parent_proxy(&self) -> zbus::Result<Option<AccessibleProxy<'static>>> {
+		let parent = self.parent().await?;
+		if parent.is_null() {
+			return Ok(None);
+		}
+		parent.into_accessible(self.inner().connection()).await.map(Some)
+	}
+
+	/// Whether the object implements `iface`.
+	///
+	/// Each call fetches the interfaces anew with [`get_interfaces`].
+	/// To check several interfaces, call it once, or use [`ProxyExt::proxies`].
+	///
+	/// # Errors
+	///
+	/// If the [`get_interfaces`] call fails.
+	///
+	/// [`get_interfaces`]: #method.get_interfaces
+	/// [`ProxyExt::proxies`]: crate::proxy_ext::ProxyExt::proxies
+	pub async fn supports(&self, iface: Interface) -> zbus::Result<bool> {
+		Ok(self.get_interfaces().await?.contains(iface))
+	}
+
+	/// Returns a proxy for the application the object belongs to.
+	///
+	/// The proxy shares this proxy's connection, and does not cache properties.
+	///
+	/// # Errors
+	///
+	/// - If the [`get_application`] call fails, or the proxy cannot be built.
+	/// - If the application reference is null, see [`ObjectRef::is_null`].
+	///
+	/// [`get_application`]: #method.get_application
+	pub async fn application(&self) -> zbus::Result<ApplicationProxy<'static>> {
+		let app = self.get_application().await?;
+		if app.is_null() {
+			return Err(zbus::Error::Failure("object has no application".into()));
+		}
+		app.into_application(self.inner().connection()).await
+	}
+
+	/// Walks up the tree, yielding the object's parent, its parent's parent, and so on.
+	///
+	/// The stream ends at an object whose parent is null, see [`ObjectRef::is_null`],
+	/// which is usually the desktop, or the application root if it is not connected to the registry.
+	/// To guard against malformed trees, it also ends before yielding an object a second time,
+	/// including the object itself.
+	///
+	/// The stream ends after yielding the first error.
+	pub fn ancestors(&self) -> impl Stream<Item = zbus::Result<AccessibleProxy<'static>>> + '_ {
+		let visited: HashSet<ObjectRef> = ObjectRef::try_from(self).into_iter().collect();
+		stream::unfold(Some((None, visited)), move |state| async move {
+			let (current, mut visited): (Option<AccessibleProxy<'static>>, _) = state?;
+			let parent = match &current {
+				Some(proxy) => proxy.parent().await,
+				None => self.parent().await,
+			};
+			let parent = match parent {
+				Ok(parent) => parent,
+				Err(e) => return Some((Err(e), None)),
+			};
+			if parent.is_null() || !visited.insert(parent.clone()) {
+				return None;
+			}
+			match parent.into_accessible(self.inner().connection()).await {
+				Ok(proxy) => Some((Ok(proxy.clone()), Some((Some(proxy), visited)))),
+				Err(e) => Some((Err(e), None)),
+			}
+		})
 	}
 }
 
@@ -314,11 +497,347 @@ impl Eq for AccessibleProxy<'_> {}
 
 #[cfg(test)]
 mod tests {
-	use crate::accessible::Role;
+	use crate::accessible::{AccessibleProxy, RelationType, Role};
+	use crate::common::{Interface, InterfaceSet, ObjectRef};
+	use crate::{serve_mock, AtspiError};
+	use futures_lite::StreamExt;
+	use std::collections::HashMap;
+
+	#[derive(Default)]
+	struct MockAccessible {
+		index_in_parent: i32,
+		relations: Vec<(u32, Vec<ObjectRef>)>,
+		children: Vec<ObjectRef>,
+		parent: ObjectRef,
+		application: ObjectRef,
+		interfaces: InterfaceSet,
+		attributes: HashMap<String, String>,
+		localized_role_name: String,
+		accessible_id: String,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Accessible")]
+	impl MockAccessible {
+		fn get_relation_set(&self) -> Vec<(u32, Vec<ObjectRef>)> {
+			self.relations.clone()
+		}
+
+		fn get_children(&self) -> Vec<ObjectRef> {
+			self.children.clone()
+		}
+
+		fn get_index_in_parent(&self) -> i32 {
+			self.index_in_parent
+		}
+
+		fn get_child_at_index(&self, index: i32) -> ObjectRef {
+			usize::try_from(index)
+				.ok()
+				.and_then(|index| self.children.get(index))
+				.cloned()
+				.unwrap_or_else(ObjectRef::null)
+		}
+
+		fn get_application(&self) -> ObjectRef {
+			self.application.clone()
+		}
+
+		fn get_interfaces(&self) -> InterfaceSet {
+			self.interfaces
+		}
+
+		fn get_attributes(&self) -> HashMap<String, String> {
+			self.attributes.clone()
+		}
+
+		fn get_localized_role_name(&self) -> &str {
+			&self.localized_role_name
+		}
+
+		#[zbus(property)]
+		fn parent(&self) -> ObjectRef {
+			self.parent.clone()
+		}
+
+		#[zbus(property)]
+		fn accessible_id(&self) -> &str {
+			&self.accessible_id
+		}
+	}
+
+	/// An object of an application which predates the `AccessibleId` property.
+	struct MockLegacyAccessible {
+		name: String,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Accessible")]
+	impl MockLegacyAccessible {
+		#[zbus(property)]
+		fn name(&self) -> &str {
+			&self.name
+		}
+	}
+
+	struct MockApplication {
+		toolkit_name: &'static str,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Application")]
+	impl MockApplication {
+		#[zbus(property)]
+		fn toolkit_name(&self) -> &str {
+			self.toolkit_name
+		}
+	}
+
+	async fn mock_accessible(mock: MockAccessible) -> (zbus::Connection, AccessibleProxy<'static>) {
+		serve_mock("/org/a11y/atspi/accessible/entry", mock).await
+	}
+
+	/// Serves each `(name, parent)` node, with a null parent for an empty name.
+	/// Returns the connection, and a proxy for the first node.
+	async fn mock_hierarchy(
+		nodes: &[(&str, &str)],
+	) -> (zbus::Connection, AccessibleProxy<'static>) {
+		let path = |name: &str| format!("/org/a11y/atspi/accessible/{name}");
+		// The first node's parent is filled in once the connection's unique name is known.
+		let (conn, proxy) = serve_mock(&path(nodes[0].0), MockAccessible::default()).await;
+		let obj = |name: &str| {
+			if name.is_empty() {
+				return ObjectRef::null();
+			}
+			ObjectRef {
+				name: conn.unique_name().unwrap().clone(),
+				path: path(name).try_into().unwrap(),
+			}
+		};
+		let first = conn
+			.object_server()
+			.interface::<_, MockAccessible>(path(nodes[0].0))
+			.await
+			.unwrap();
+		first.get_mut().await.parent = obj(nodes[0].1);
+		for (name, parent) in &nodes[1..] {
+			let node = MockAccessible { parent: obj(parent), ..Default::default() };
+			conn.object_server().at(path(name), node).await.unwrap();
+		}
+		(conn, proxy)
+	}
+
+	async fn ancestor_paths(proxy: &AccessibleProxy<'_>) -> Vec<String> {
+		proxy
+			.ancestors()
+			.map(|ancestor| ancestor.unwrap().inner().path().to_string())
+			.collect()
+			.await
+	}
+
+	async fn relations_of(
+		relations: Vec<(u32, Vec<ObjectRef>)>,
+	) -> Result<Vec<(RelationType, Vec<ObjectRef>)>, AtspiError> {
+		let (_conn, proxy) =
+			mock_accessible(MockAccessible { relations, ..Default::default() }).await;
+		proxy.get_relation_set_typed().await
+	}
+
+	fn object(name: &str) -> ObjectRef {
+		ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/{name}").try_into().unwrap(),
+		}
+	}
+
+	#[tokio::test]
+	async fn relation_set_typed_decodes_relations() {
+		let relations = relations_of(vec![
+			(RelationType::LabelledBy as u32, vec![object("label")]),
+			(RelationType::ControllerFor as u32, vec![object("list"), object("scrollbar")]),
+		])
+		.await
+		.unwrap();
+		assert_eq!(
+			relations,
+			[
+				(RelationType::LabelledBy, vec![object("label")]),
+				(RelationType::ControllerFor, vec![object("list"), object("scrollbar")]),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn relation_set_typed_rejects_unknown_relations() {
+		let result = relations_of(vec![(99, vec![object("label")])]).await;
+		assert!(matches!(result, Err(AtspiError::UnknownRelation(99))));
+	}
 
 	#[test]
 	fn test_output_of_role_name() {
 		assert_eq!(Role::Invalid.name(), "invalid");
 		assert_eq!(Role::PushButtonMenu.name(), "push button menu");
 	}
+
+	#[tokio::test]
+	async fn children_skips_null_refs() {
+		let children = vec![object("first"), ObjectRef::null(), object("second")];
+		let (_conn, parent) =
+			mock_accessible(MockAccessible { children, ..Default::default() }).await;
+		let children = parent.children().await.unwrap();
+		let paths: Vec<_> = children.iter().map(|child| child.inner().path().as_str()).collect();
+		assert_eq!(
+			paths,
+			["/org/a11y/atspi/accessible/first", "/org/a11y/atspi/accessible/second"]
+		);
+		assert!(children.iter().all(|child| child.inner().destination() == ":1.42"));
+	}
+
+	#[tokio::test]
+	async fn child_proxy_at_index() {
+		let children = vec![object("only")];
+		let (_conn, parent) =
+			mock_accessible(MockAccessible { children, ..Default::default() }).await;
+		let child = parent.child_proxy_at(0).await.unwrap();
+		assert_eq!(child.inner().path().as_str(), "/org/a11y/atspi/accessible/only");
+		assert_eq!(child.inner().destination(), ":1.42");
+		assert!(parent.child_proxy_at(1).await.is_err());
+		assert!(parent.child_proxy_at(-1).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn get_index_in_parent_of_object() {
+		let mock = MockAccessible { index_in_parent: 2, ..Default::default() };
+		let (_conn, entry) = mock_accessible(mock).await;
+		assert_eq!(entry.get_index_in_parent().await.unwrap(), 2);
+	}
+
+	#[tokio::test]
+	async fn parent_proxy_of_object_and_root() {
+		let (_conn, button) = mock_hierarchy(&[("button", "panel"), ("panel", "")]).await;
+		let panel = button.parent_proxy().await.unwrap().unwrap();
+		assert_eq!(panel.inner().path().as_str(), "/org/a11y/atspi/accessible/panel");
+		assert!(panel.parent_proxy().await.unwrap().is_none());
+	}
+
+	#[tokio::test]
+	async fn children_of_leaf_is_empty() {
+		let (_conn, leaf) = mock_accessible(MockAccessible::default()).await;
+		assert!(leaf.children().await.unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn ancestors_up_to_root() {
+		let nodes = [("button", "panel"), ("panel", "app"), ("app", "root"), ("root", "")];
+		let (_conn, button) = mock_hierarchy(&nodes).await;
+		assert_eq!(
+			ancestor_paths(&button).await,
+			[
+				"/org/a11y/atspi/accessible/panel",
+				"/org/a11y/atspi/accessible/app",
+				"/org/a11y/atspi/accessible/root",
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn ancestors_stop_at_cycles() {
+		let (_conn, own_parent) = mock_hierarchy(&[("loop", "loop")]).await;
+		assert!(ancestor_paths(&own_parent).await.is_empty());
+
+		let (_conn, first) = mock_hierarchy(&[("first", "second"), ("second", "first")]).await;
+		assert_eq!(ancestor_paths(&first).await, ["/org/a11y/atspi/accessible/second"]);
+	}
+
+	#[tokio::test]
+	async fn application_of_object() {
+		let (conn, entry) = mock_accessible(MockAccessible::default()).await;
+		let app_path = "/org/a11y/atspi/accessible/root";
+		conn.object_server()
+			.at(app_path, MockApplication { toolkit_name: "GTK" })
+			.await
+			.unwrap();
+		// The application's reference is only known once the connection's unique name is.
+		let mock = conn
+			.object_server()
+			.interface::<_, MockAccessible>(entry.inner().path())
+			.await
+			.unwrap();
+		mock.get_mut().await.application = ObjectRef {
+			name: conn.unique_name().unwrap().clone(),
+			path: app_path.try_into().unwrap(),
+		};
+
+		let app = entry.application().await.unwrap();
+		assert_eq!(app.inner().path().as_str(), app_path);
+		assert_eq!(app.toolkit_name().await.unwrap(), "GTK");
+	}
+
+	#[tokio::test]
+	async fn null_application_is_an_error() {
+		let application = ObjectRef::null();
+		let (_conn, entry) =
+			mock_accessible(MockAccessible { application, ..Default::default() }).await;
+		assert!(entry.application().await.is_err());
+	}
+
+	#[tokio::test]
+	async fn supports_reported_interfaces() {
+		let interfaces = InterfaceSet::new(Interface::Accessible | Interface::Component);
+		let (_conn, proxy) =
+			mock_accessible(MockAccessible { interfaces, ..Default::default() }).await;
+		assert!(proxy.supports(Interface::Component).await.unwrap());
+		assert!(!proxy.supports(Interface::Text).await.unwrap());
+	}
+
+	fn attributes(pairs: &[(&str, &str)]) -> MockAccessible {
+		let attributes = pairs
+			.iter()
+			.map(|&(name, value)| (name.to_owned(), value.to_owned()))
+			.collect();
+		MockAccessible { attributes, ..Default::default() }
+	}
+
+	#[tokio::test]
+	async fn attributes_by_name() {
+		let (_conn, proxy) =
+			mock_accessible(attributes(&[("xml-roles", "heading"), ("level", "2")])).await;
+		let map = proxy.get_attributes().await.unwrap();
+		assert_eq!(map.len(), 2);
+		assert_eq!(map["xml-roles"], "heading");
+		assert_eq!(map["level"], "2");
+	}
+
+	#[tokio::test]
+	async fn level_parses_attribute() {
+		let (_conn, proxy) = mock_accessible(attributes(&[("level", "3")])).await;
+		assert_eq!(proxy.level().await.unwrap(), Some(3));
+		let (_conn, proxy) = mock_accessible(attributes(&[("level", "three")])).await;
+		assert_eq!(proxy.level().await.unwrap(), None);
+		let (_conn, proxy) = mock_accessible(attributes(&[("level", "-1")])).await;
+		assert_eq!(proxy.level().await.unwrap(), None);
+		let (_conn, proxy) = mock_accessible(attributes(&[])).await;
+		assert_eq!(proxy.level().await.unwrap(), None);
+	}
+
+	#[tokio::test]
+	async fn get_localized_role_name_in_french() {
+		let mock = MockAccessible { localized_role_name: "bouton".into(), ..Default::default() };
+		let (_conn, button) = mock_accessible(mock).await;
+		assert_eq!(button.get_localized_role_name().await.unwrap(), "bouton");
+	}
+
+	#[tokio::test]
+	async fn accessible_id_of_object() {
+		let mock = MockAccessible { accessible_id: "login-button".into(), ..Default::default() };
+		let (_conn, button) = mock_accessible(mock).await;
+		assert_eq!(button.accessible_id_or_empty().await.unwrap(), "login-button");
+	}
+
+	#[tokio::test]
+	async fn accessible_id_unsupported_is_empty() {
+		let path = "/org/a11y/atspi/accessible/legacy";
+		let (_conn, button): (_, AccessibleProxy) =
+			serve_mock(path, MockLegacyAccessible { name: "Log in".into() }).await;
+		assert_eq!(button.name().await.unwrap(), "Log in");
+		assert!(button.accessible_id().await.is_err());
+		assert_eq!(button.accessible_id_or_empty().await.unwrap(), "");
+	}
 }
@@ -17,6 +17,7 @@
 //! [TextProxy]: crate::text::TextProxy
 //! [ValueProxy]: crate::value::ValueProxy
 
+use crate::AtspiError;
 use atspi_common::Action;
 
 /// A handle for a remote object implementing the `org.a11y.atspi.Action`
@@ -126,6 +127,138 @@ trait Action {
 	///
 	///	By convention, if there is more than one action available,
 	/// the first one is considered the "default" action of the object.
-	#[zbus(property)]
+	#[zbus(property, name = "NActions")]
 	fn nactions(&self) -> zbus::Result<i32>;
 }
+
+impl ActionProxy<'_> {
+	/// The machine-readable name, description and key binding of each action, in index order.
+	///
+	/// Unlike [`get_actions`], which returns localized names, the names returned here
+	/// can be passed to [`ActionProxy::do_action_named`].
+	/// This takes three calls per action.
+	///
+	/// [`get_actions`]: ActionProxy#method.get_actions
+	///
+	/// # Errors
+	///
+	/// If any of the `DBus` calls fail.
+	pub async fn actions(&self) -> zbus::Result<Vec<Action>> {
+		let count = self.nactions().await?;
+		let mut actions = Vec::with_capacity(usize::try_from(count).unwrap_or(0));
+		for index in 0..count {
+			actions.push(Action {
+				name: self.get_name(index).await?,
+				description: self.get_description(index).await?,
+				keybinding: self.get_key_binding(index).await?,
+			});
+		}
+		Ok(actions)
+	}
+
+	/// Performs the action with the machine-readable `name`, e.g. `"click"`.
+	///
+	/// Returns: Ok(true) on success, Ok(false) otherwise.
+	///
+	/// # Errors
+	///
+	/// - If any of the `DBus` calls fail.
+	/// - If the object has no action named `name`.
+	pub async fn do_action_named(&self, name: &str) -> Result<bool, AtspiError> {
+		for index in 0..self.nactions().await? {
+			if self.get_name(index).await? == name {
+				return Ok(self.do_action(index).await?);
+			}
+		}
+		Err(AtspiError::Owned(format!("no action named {name:?}")))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ActionProxy;
+	use crate::serve_mock;
+	use atspi_common::Action;
+
+	/// A button, which records the indices of the actions performed on it.
+	struct MockAction {
+		actions: Vec<Action>,
+		performed: Vec<i32>,
+	}
+
+	impl MockAction {
+		fn action(&self, index: i32) -> zbus::fdo::Result<&Action> {
+			usize::try_from(index)
+				.ok()
+				.and_then(|index| self.actions.get(index))
+				.ok_or_else(|| zbus::fdo::Error::InvalidArgs("no such action".into()))
+		}
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Action")]
+	impl MockAction {
+		fn do_action(&mut self, index: i32) -> zbus::fdo::Result<bool> {
+			self.action(index)?;
+			self.performed.push(index);
+			Ok(true)
+		}
+
+		fn get_description(&self, index: i32) -> zbus::fdo::Result<String> {
+			Ok(self.action(index)?.description.clone())
+		}
+
+		fn get_key_binding(&self, index: i32) -> zbus::fdo::Result<String> {
+			Ok(self.action(index)?.keybinding.clone())
+		}
+
+		fn get_name(&self, index: i32) -> zbus::fdo::Result<String> {
+			Ok(self.action(index)?.name.clone())
+		}
+
+		#[zbus(property, name = "NActions")]
+		fn nactions(&self) -> i32 {
+			self.actions.len().try_into().unwrap()
+		}
+	}
+
+	fn action(name: &str, description: &str, keybinding: &str) -> Action {
+		Action {
+			name: name.to_owned(),
+			description: description.to_owned(),
+			keybinding: keybinding.to_owned(),
+		}
+	}
+
+	fn button_actions() -> Vec<Action> {
+		vec![
+			action("click", "Clicks the button", "C;;Alt+C"),
+			action("press", "Presses the button", ""),
+		]
+	}
+
+	async fn mock_button() -> (zbus::Connection, ActionProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/button";
+		let mock = MockAction { actions: button_actions(), performed: vec![] };
+		serve_mock(path, mock).await
+	}
+
+	#[tokio::test]
+	async fn actions_in_index_order() {
+		let (_conn, button) = mock_button().await;
+		assert_eq!(button.actions().await.unwrap(), button_actions());
+	}
+
+	#[tokio::test]
+	async fn do_action_by_name() {
+		let (conn, button) = mock_button().await;
+		assert!(button.do_action_named("press").await.unwrap());
+		assert!(button.do_action_named("click").await.unwrap());
+		assert!(button.do_action_named("activate").await.is_err());
+		let mock = conn
+			.object_server()
+			.interface::<_, MockAction>("/org/a11y/atspi/accessible/button")
+			.await
+			.unwrap();
+		assert_eq!(mock.get().await.performed, [1, 0]);
+	}
+}
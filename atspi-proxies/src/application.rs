@@ -151,3 +151,51 @@ trait Application {
 	#[zbus(property)]
 	fn version(&self) -> zbus::Result<String>;
 }
+
+impl ApplicationProxy<'_> {
+	/// Returns the name and version of the toolkit used to implement the application's user interface.
+	///
+	/// Combines [`toolkit_name`] and [`version`].
+	///
+	/// # Errors
+	///
+	/// If either of the `DBus` calls fail.
+	///
+	/// [`toolkit_name`]: #method.toolkit_name
+	/// [`version`]: #method.version
+	pub async fn toolkit_info(&self) -> zbus::Result<(String, String)> {
+		Ok((self.toolkit_name().await?, self.version().await?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ApplicationProxy;
+	use crate::serve_mock;
+
+	struct MockApplication {
+		toolkit_name: &'static str,
+		version: &'static str,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Application")]
+	impl MockApplication {
+		#[zbus(property)]
+		fn toolkit_name(&self) -> &str {
+			self.toolkit_name
+		}
+
+		#[zbus(property)]
+		fn version(&self) -> &str {
+			self.version
+		}
+	}
+
+	#[tokio::test]
+	async fn toolkit_info_of_gtk_application() {
+		let path = "/org/a11y/atspi/accessible/root";
+		let mock = MockApplication { toolkit_name: "gtk", version: "3.24" };
+		let (_conn, app): (_, ApplicationProxy) = serve_mock(path, mock).await;
+		assert_eq!(app.toolkit_info().await.unwrap(), ("gtk".to_owned(), "3.24".to_owned()));
+	}
+}
@@ -8,10 +8,62 @@ use crate::common::{CacheItem, LegacyCacheItem};
 
 #[zbus::proxy(interface = "org.a11y.atspi.Cache", default_path = "/org/a11y/atspi/cache")]
 trait Cache {
-	/// GetItems method
+	/// Returns every accessible object in the application's cache, decoded into [`CacheItem`]s.
+	///
+	/// An empty cache yields an empty list.
+	/// Servers which still send the older item layout need [`get_legacy_items`] instead.
+	///
+	/// [`get_legacy_items`]: #method.get_legacy_items
 	fn get_items(&self) -> zbus::Result<Vec<CacheItem>>;
 
 	/// GetItems method to support legacy servers (presumably Qt based applications and at-spi2-registryd)
 	#[zbus(name = "GetItems")]
 	fn get_legacy_items(&self) -> zbus::Result<Vec<LegacyCacheItem>>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::CacheProxy;
+	use crate::common::{CacheItem, Interface, InterfaceSet, Role};
+	use crate::serve_mock;
+
+	struct MockCache {
+		items: Vec<CacheItem>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Cache")]
+	impl MockCache {
+		fn get_items(&self) -> Vec<CacheItem> {
+			self.items.clone()
+		}
+	}
+
+	async fn items_of(items: Vec<CacheItem>) -> Vec<CacheItem> {
+		let (_conn, cache): (_, CacheProxy) =
+			serve_mock("/org/a11y/atspi/cache", MockCache { items }).await;
+		cache.get_items().await.unwrap()
+	}
+
+	#[tokio::test]
+	async fn items_are_decoded() {
+		let items = vec![
+			CacheItem {
+				role: Role::Frame,
+				name: "Editor".to_owned(),
+				children: 1,
+				..Default::default()
+			},
+			CacheItem {
+				role: Role::Text,
+				ifaces: InterfaceSet::new(Interface::Accessible | Interface::Text),
+				..Default::default()
+			},
+		];
+		assert_eq!(items_of(items.clone()).await, items);
+	}
+
+	#[tokio::test]
+	async fn empty_cache_has_no_items() {
+		assert!(items_of(Vec::new()).await.is_empty());
+	}
+}
@@ -15,6 +15,7 @@
 //! [`CollectionProxy`]: crate::collection::CollectionProxy
 
 use crate::common::{ObjectMatchRule, ObjectRef, SortOrder, TreeTraversalType};
+use zbus::zvariant::OwnedObjectPath;
 
 #[zbus::proxy(interface = "org.a11y.atspi.Collection", assume_defaults = true)]
 trait Collection {
@@ -97,3 +98,291 @@ trait Collection {
 		traverse: bool,
 	) -> zbus::Result<Vec<ObjectRef>>;
 }
+
+impl CollectionProxy<'_> {
+	/// Retrieves the objects matching `rule`, ordered according to `sort` and limited to `count` results.
+	///
+	/// Like [`get_matches`], but borrows the `rule`, so it can be reused for further queries.
+	/// A `count` of 0 means there is no limit; negative counts are treated the same.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	///
+	/// [`get_matches`]: #method.get_matches
+	pub async fn get_matches_typed(
+		&self,
+		rule: &ObjectMatchRule,
+		sort: SortOrder,
+		count: i32,
+		traverse: bool,
+	) -> zbus::Result<Vec<ObjectRef>> {
+		self.get_matches(rule.clone(), sort, count.max(0), traverse).await
+	}
+
+	/// Retrieves the objects matching `rule` which follow `pivot`, searching the part of the tree given by `tree`.
+	///
+	/// Like [`get_matches_from`], but takes the pivot as an [`ObjectRef`] and borrows the `rule`.
+	/// `pivot` must belong to the same application as the collection, i.e. its bus name must be the
+	/// proxy's destination; only its path is sent.
+	/// A `count` of 0 means there is no limit; negative counts are treated the same.
+	///
+	/// # Errors
+	///
+	/// - [`zbus::Error::Failure`] if `pivot` belongs to another application than the collection.
+	/// - If the `DBus` call fails.
+	///
+	/// [`get_matches_from`]: #method.get_matches_from
+	pub async fn get_matches_from_typed(
+		&self,
+		pivot: &ObjectRef,
+		rule: &ObjectMatchRule,
+		sort: SortOrder,
+		tree: TreeTraversalType,
+		count: i32,
+		traverse: bool,
+	) -> zbus::Result<Vec<ObjectRef>> {
+		let pivot = self.pivot_path(pivot)?;
+		self.get_matches_from(pivot, rule.clone(), sort, tree, count.max(0), traverse)
+			.await
+	}
+
+	/// Retrieves the objects matching `rule` which precede `pivot`, searching the part of the tree given by `tree`.
+	///
+	/// Like [`get_matches_to`], but takes the pivot as an [`ObjectRef`] and borrows the `rule`.
+	/// `pivot` must belong to the same application as the collection, i.e. its bus name must be the
+	/// proxy's destination; only its path is sent.
+	/// If `limit_scope` is `true`, only descendants of the pivot's parent are returned.
+	/// A `count` of 0 means there is no limit; negative counts are treated the same.
+	///
+	/// # Errors
+	///
+	/// - [`zbus::Error::Failure`] if `pivot` belongs to another application than the collection.
+	/// - If the `DBus` call fails.
+	///
+	/// [`get_matches_to`]: #method.get_matches_to
+	#[allow(clippy::too_many_arguments)]
+	pub async fn get_matches_to_typed(
+		&self,
+		pivot: &ObjectRef,
+		rule: &ObjectMatchRule,
+		sort: SortOrder,
+		tree: TreeTraversalType,
+		limit_scope: bool,
+		count: i32,
+		traverse: bool,
+	) -> zbus::Result<Vec<ObjectRef>> {
+		let pivot = self.pivot_path(pivot)?;
+		self.get_matches_to(pivot, rule.clone(), sort, tree, limit_scope, count.max(0), traverse)
+			.await
+	}
+
+	/// The path of `pivot`, if it belongs to the application this proxy calls.
+	fn pivot_path<'p>(&self, pivot: &'p ObjectRef) -> zbus::Result<&'p OwnedObjectPath> {
+		let destination = self.inner().destination();
+		if pivot.name.as_str() != destination.as_str() {
+			return Err(zbus::Error::Failure(format!(
+				"pivot belongs to {}, not to the collection's application {destination}",
+				pivot.name
+			)));
+		}
+		Ok(&pivot.path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CollectionProxy;
+	use crate::common::{
+		MatchType, ObjectMatchRule, ObjectRef, Role, SortOrder, TreeTraversalType,
+	};
+	use crate::serve_mock;
+	use zbus::zvariant::OwnedObjectPath;
+
+	/// `ATSPI_Collection_TREE_RESTRICT_CHILDREN`, as numbered by AT-SPI.
+	const TREE_RESTRICT_CHILDREN: u32 = 0;
+	/// `ATSPI_Collection_TREE_INORDER`, as numbered by AT-SPI.
+	const TREE_INORDER: u32 = 2;
+
+	/// Matches all of its objects, unless the rule is inverted.
+	///
+	/// The objects are siblings without children, so restricting a directional search
+	/// to the pivot's children finds nothing.
+	struct MockCollection {
+		objects: Vec<ObjectRef>,
+		/// The `limit_scope` of the last `GetMatchesTo` call.
+		limit_scope: Option<bool>,
+	}
+
+	impl MockCollection {
+		/// The objects before and after `pivot`, if `tree` may include them.
+		fn split_at(&self, pivot: &OwnedObjectPath, tree: u32) -> (&[ObjectRef], &[ObjectRef]) {
+			let Some(at) = self.objects.iter().position(|obj| obj.path == *pivot) else {
+				return (&[], &[]);
+			};
+			match tree {
+				TREE_INORDER => (&self.objects[..at], &self.objects[at + 1..]),
+				TREE_RESTRICT_CHILDREN => (&[], &[]),
+				_ => panic!("unexpected tree traversal type {tree}"),
+			}
+		}
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Collection")]
+	impl MockCollection {
+		// Interface methods receive their arguments by value.
+		#[allow(clippy::needless_pass_by_value)]
+		fn get_matches(
+			&self,
+			rule: ObjectMatchRule,
+			sortby: u32,
+			count: i32,
+			traverse: bool,
+		) -> Vec<ObjectRef> {
+			if rule.invert || traverse {
+				return Vec::new();
+			}
+			let mut matches = self.objects.clone();
+			if sortby == SortOrder::ReverseCanonical as u32 {
+				matches.reverse();
+			}
+			if count > 0 {
+				matches.truncate(count.try_into().unwrap());
+			}
+			matches
+		}
+
+		#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+		fn get_matches_from(
+			&self,
+			current_object: OwnedObjectPath,
+			rule: ObjectMatchRule,
+			sortby: u32,
+			tree: u32,
+			count: i32,
+			traverse: bool,
+		) -> Vec<ObjectRef> {
+			let (_, after) = self.split_at(&current_object, tree);
+			let mock = MockCollection { objects: after.to_vec(), limit_scope: None };
+			mock.get_matches(rule, sortby, count, traverse)
+		}
+
+		/// All objects share a parent, so limiting the scope to it changes nothing;
+		/// `limit_scope` is only recorded.
+		#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+		fn get_matches_to(
+			&mut self,
+			current_object: OwnedObjectPath,
+			rule: ObjectMatchRule,
+			sortby: u32,
+			tree: u32,
+			limit_scope: bool,
+			count: i32,
+			traverse: bool,
+		) -> Vec<ObjectRef> {
+			self.limit_scope = Some(limit_scope);
+			let (before, _) = self.split_at(&current_object, tree);
+			let mock = MockCollection { objects: before.to_vec(), limit_scope: None };
+			mock.get_matches(rule, sortby, count, traverse)
+		}
+	}
+
+	fn object(id: u32) -> ObjectRef {
+		ObjectRef {
+			name: ":1.7".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/{id}").try_into().unwrap(),
+		}
+	}
+
+	/// Object `id`, as the pivot of a search in the collection served on `conn`.
+	fn pivot(conn: &zbus::Connection, id: u32) -> ObjectRef {
+		ObjectRef { name: conn.unique_name().unwrap().to_owned(), path: object(id).path }
+	}
+
+	async fn mock_collection() -> (zbus::Connection, CollectionProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/root";
+		let mock =
+			MockCollection { objects: vec![object(1), object(2), object(3)], limit_scope: None };
+		serve_mock(path, mock).await
+	}
+
+	#[tokio::test]
+	async fn get_matches_typed_without_limit() {
+		let (_conn, collection) = mock_collection().await;
+		let rule = ObjectMatchRule::builder()
+			.roles(&[Role::Button], MatchType::All)
+			.build();
+		let matches = collection
+			.get_matches_typed(&rule, SortOrder::Canonical, 0, false)
+			.await
+			.unwrap();
+		assert_eq!(matches, [object(1), object(2), object(3)]);
+		let matches = collection
+			.get_matches_typed(&rule, SortOrder::ReverseCanonical, -1, false)
+			.await
+			.unwrap();
+		assert_eq!(matches, [object(3), object(2), object(1)]);
+	}
+
+	#[tokio::test]
+	async fn get_matches_typed_with_limit() {
+		let (_conn, collection) = mock_collection().await;
+		let rule = ObjectMatchRule::builder().build();
+		let matches = collection
+			.get_matches_typed(&rule, SortOrder::Canonical, 2, false)
+			.await
+			.unwrap();
+		assert_eq!(matches, [object(1), object(2)]);
+	}
+
+	#[tokio::test]
+	async fn get_matches_from_and_to_pivot() {
+		let (conn, collection) = mock_collection().await;
+		let rule = ObjectMatchRule::builder().build();
+		let (sort, tree) = (SortOrder::Canonical, TreeTraversalType::Inorder);
+		let after = collection
+			.get_matches_from_typed(&pivot(&conn, 2), &rule, sort, tree, 0, false)
+			.await
+			.unwrap();
+		assert_eq!(after, [object(3)]);
+		let before = collection
+			.get_matches_to_typed(&pivot(&conn, 3), &rule, sort, tree, true, 1, false)
+			.await
+			.unwrap();
+		assert_eq!(before, [object(1)]);
+		let mock = conn
+			.object_server()
+			.interface::<_, MockCollection>("/org/a11y/atspi/accessible/root")
+			.await
+			.unwrap();
+		assert_eq!(mock.get().await.limit_scope, Some(true));
+
+		let children = collection
+			.get_matches_from_typed(
+				&pivot(&conn, 1),
+				&rule,
+				sort,
+				TreeTraversalType::RestrictChildren,
+				0,
+				false,
+			)
+			.await
+			.unwrap();
+		assert!(children.is_empty());
+	}
+
+	#[tokio::test]
+	async fn pivot_of_another_application_is_rejected() {
+		let (_conn, collection) = mock_collection().await;
+		let rule = ObjectMatchRule::builder().build();
+		let (sort, tree) = (SortOrder::Canonical, TreeTraversalType::Inorder);
+		let after = collection
+			.get_matches_from_typed(&object(2), &rule, sort, tree, 0, false)
+			.await;
+		assert!(matches!(after, Err(zbus::Error::Failure(_))));
+		let before = collection
+			.get_matches_to_typed(&object(3), &rule, sort, tree, true, 0, false)
+			.await;
+		assert!(matches!(before, Err(zbus::Error::Failure(_))));
+	}
+}
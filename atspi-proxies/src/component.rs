@@ -10,7 +10,7 @@
 //! section of the zbus documentation.
 //!
 
-use crate::common::{CoordType, Layer, ObjectRef, ScrollType};
+use crate::common::{CoordType, Layer, ObjectRef, Rect, ScrollType};
 
 #[zbus::proxy(interface = "org.a11y.atspi.Component", assume_defaults = true)]
 trait Component {
@@ -31,7 +31,9 @@ trait Component {
 	/// GetExtents method
 	fn get_extents(&self, coord_type: CoordType) -> zbus::Result<(i32, i32, i32, i32)>;
 
-	/// GetLayer method
+	/// The layer the component is painted in.
+	///
+	/// Fails to deserialize the reply if the application reports a value which is not a [`Layer`].
 	fn get_layer(&self) -> zbus::Result<Layer>;
 
 	/// GetMDIZOrder method
@@ -44,7 +46,9 @@ trait Component {
 	/// GetSize method
 	fn get_size(&self) -> zbus::Result<(i32, i32)>;
 
-	/// GrabFocus method
+	/// Request the component to take keyboard focus.
+	///
+	/// Returns `true` if the component took focus.
 	fn grab_focus(&self) -> zbus::Result<bool>;
 
 	/// ScrollTo method
@@ -53,7 +57,13 @@ trait Component {
 	/// ScrollToPoint method
 	fn scroll_to_point(&self, coord_type: CoordType, x: i32, y: i32) -> zbus::Result<bool>;
 
-	/// SetExtents method
+	/// Request the object to move and resize to the given extents.
+	///
+	/// Returns `true` if the application accepted the request.
+	///
+	/// ## Notes
+	/// Most applications ignore this request and return `false`;
+	/// toolkits generally only honor it for top-level windows, if at all.
 	fn set_extents(
 		&self,
 		x: i32,
@@ -63,9 +73,218 @@ trait Component {
 		coord_type: CoordType,
 	) -> zbus::Result<bool>;
 
-	/// SetPosition method
+	/// Request the object to move to the given position.
+	///
+	/// Returns `true` if the application accepted the request.
+	///
+	/// ## Notes
+	/// Most applications ignore this request and return `false`.
+	/// See [`set_extents`].
+	///
+	/// [`set_extents`]: #method.set_extents
 	fn set_position(&self, x: i32, y: i32, coord_type: CoordType) -> zbus::Result<bool>;
 
-	/// SetSize method
+	/// Request the object to resize to the given width and height.
+	///
+	/// Returns `true` if the application accepted the request.
+	///
+	/// ## Notes
+	/// Most applications ignore this request and return `false`.
+	/// See [`set_extents`].
+	///
+	/// [`set_extents`]: #method.set_extents
 	fn set_size(&self, width: i32, height: i32) -> zbus::Result<bool>;
 }
+
+impl ComponentProxy<'_> {
+	/// Whether the point at `x`, `y` lies within the component's extents.
+	///
+	/// The point is interpreted relative to `coord_type`.
+	///
+	/// ```rust
+	/// # use atspi_proxies::{component::ComponentProxy, CoordType};
+	/// # async fn hit_test(component: ComponentProxy<'_>) -> zbus::Result<()> {
+	/// if component.contains_point(640, 360, CoordType::Screen).await? {
+	///     println!("The pointer is over this component.");
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn contains_point(
+		&self,
+		x: i32,
+		y: i32,
+		coord_type: CoordType,
+	) -> zbus::Result<bool> {
+		self.contains(x, y, coord_type).await
+	}
+
+	/// The position and size of the component, with the position relative to `coord_type`.
+	///
+	/// Like [`ComponentProxy::get_extents`], with the fields named.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn extents_rect(&self, coord_type: CoordType) -> zbus::Result<Rect> {
+		self.get_extents(coord_type).await.map(Rect::from)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ComponentProxy;
+	use crate::common::{CoordType, Layer, Rect};
+	use crate::serve_mock;
+
+	/// The screen position of the window the mock component is in.
+	const WINDOW_ORIGIN: (i32, i32) = (100, 50);
+
+	/// Accepts requests which stay within the screen, using screen or window coordinates.
+	///
+	/// Points are hit-tested against the component's extents.
+	struct MockComponent {
+		screen: (i32, i32),
+		/// The extents relative to the window.
+		extents: (i32, i32, i32, i32),
+		/// Whether the component accepts focus.
+		focusable: bool,
+		focused: bool,
+		layer: u32,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Component")]
+	impl MockComponent {
+		fn contains(&self, x: i32, y: i32, coord_type: u32) -> bool {
+			let (left, top, width, height) = self.get_extents(coord_type);
+			(left..left + width).contains(&x) && (top..top + height).contains(&y)
+		}
+
+		fn get_extents(&self, coord_type: u32) -> (i32, i32, i32, i32) {
+			let (x, y, width, height) = self.extents;
+			if coord_type == CoordType::Screen as u32 {
+				(x + WINDOW_ORIGIN.0, y + WINDOW_ORIGIN.1, width, height)
+			} else {
+				self.extents
+			}
+		}
+
+		fn get_layer(&self) -> u32 {
+			self.layer
+		}
+
+		fn grab_focus(&mut self) -> bool {
+			self.focused |= self.focusable;
+			self.focused
+		}
+
+		fn set_extents(&self, x: i32, y: i32, width: i32, height: i32, coord_type: u32) -> bool {
+			self.set_position(x, y, coord_type) && self.set_size(width, height)
+		}
+
+		fn set_position(&self, x: i32, y: i32, coord_type: u32) -> bool {
+			(0..self.screen.0).contains(&x)
+				&& (0..self.screen.1).contains(&y)
+				&& coord_type <= CoordType::Window as u32
+		}
+
+		fn set_size(&self, width: i32, height: i32) -> bool {
+			(1..=self.screen.0).contains(&width) && (1..=self.screen.1).contains(&height)
+		}
+	}
+
+	async fn mock_component() -> (zbus::Connection, ComponentProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/mock";
+		serve_mock(
+			path,
+			MockComponent {
+				screen: (1920, 1080),
+				extents: (10, 20, 300, 40),
+				focusable: true,
+				focused: false,
+				layer: Layer::Widget as u32,
+			},
+		)
+		.await
+	}
+
+	#[tokio::test]
+	async fn setters_report_accepted() {
+		let (_conn, component) = mock_component().await;
+		assert!(component.set_position(10, 20, CoordType::Screen).await.unwrap());
+		assert!(component.set_size(100, 50).await.unwrap());
+		assert!(component
+			.set_extents(10, 20, 100, 50, CoordType::Window)
+			.await
+			.unwrap());
+	}
+
+	#[tokio::test]
+	async fn setters_report_rejected() {
+		let (_conn, component) = mock_component().await;
+		assert!(!component.set_position(10, 20, CoordType::Parent).await.unwrap());
+		assert!(!component.set_size(0, 50).await.unwrap());
+		assert!(!component
+			.set_extents(-10, 20, 100, 50, CoordType::Screen)
+			.await
+			.unwrap());
+	}
+
+	#[tokio::test]
+	async fn contains_point_inside_and_outside() {
+		let (_conn, component) = mock_component().await;
+		// The component covers (110, 70) to (410, 110) on screen, and (10, 20) to (310, 60) in its window.
+		assert!(component.contains_point(200, 90, CoordType::Screen).await.unwrap());
+		assert!(!component.contains_point(640, 360, CoordType::Screen).await.unwrap());
+		assert!(component.contains_point(10, 20, CoordType::Window).await.unwrap());
+		assert!(!component.contains_point(310, 20, CoordType::Window).await.unwrap());
+	}
+
+	#[tokio::test]
+	async fn extents_rect_names_tuple_fields() {
+		let (_conn, component) = mock_component().await;
+		let rect = component.extents_rect(CoordType::Window).await.unwrap();
+		assert_eq!(rect, Rect { x: 10, y: 20, width: 300, height: 40 });
+		let rect = component.extents_rect(CoordType::Screen).await.unwrap();
+		assert_eq!(rect, Rect { x: 110, y: 70, width: 300, height: 40 });
+	}
+
+	#[tokio::test]
+	async fn grab_focus_reports_whether_focus_was_taken() {
+		let (conn, component) = mock_component().await;
+		assert!(component.grab_focus().await.unwrap());
+		let mock = conn
+			.object_server()
+			.interface::<_, MockComponent>("/org/a11y/atspi/accessible/mock")
+			.await
+			.unwrap();
+		assert!(mock.get().await.focused);
+		let mut mock = mock.get_mut().await;
+		mock.focusable = false;
+		mock.focused = false;
+		drop(mock);
+		assert!(!component.grab_focus().await.unwrap());
+	}
+
+	#[tokio::test]
+	async fn get_layer_is_typed() {
+		let (_conn, component) = mock_component().await;
+		assert_eq!(component.get_layer().await.unwrap(), Layer::Widget);
+	}
+
+	#[tokio::test]
+	async fn get_layer_rejects_unknown_values() {
+		let (conn, component) = mock_component().await;
+		let mock = conn
+			.object_server()
+			.interface::<_, MockComponent>("/org/a11y/atspi/accessible/mock")
+			.await
+			.unwrap();
+		mock.get_mut().await.layer = 42;
+		assert!(component.get_layer().await.is_err());
+	}
+}
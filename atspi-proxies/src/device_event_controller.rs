@@ -124,3 +124,75 @@ trait DeviceEventController {
 		mode: &EventListenerMode,
 	) -> zbus::Result<bool>;
 }
+
+impl DeviceEventControllerProxy<'_> {
+	/// Type `text` as if it was entered on the keyboard.
+	///
+	/// Each character is synthesized with its own [`KeySynthType::String`] event, in order.
+	/// Characters outside the Basic Multilingual Plane, like emoji, are sent whole, never split into surrogates.
+	///
+	/// # Errors
+	///
+	/// If any of the `DBus` calls fails; the characters before the failing one have been typed already.
+	pub async fn generate_keyboard_event_str(&self, text: &str) -> zbus::Result<()> {
+		let mut buf = [0; 4];
+		for c in text.chars() {
+			self.generate_keyboard_event(0, c.encode_utf8(&mut buf), KeySynthType::String)
+				.await?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{DeviceEventControllerProxy, KeySynthType};
+	use crate::serve_mock;
+
+	/// Records the key strings of all synthesized string events.
+	#[derive(Default)]
+	struct MockDeviceEventController {
+		typed: Vec<String>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.DeviceEventController")]
+	impl MockDeviceEventController {
+		fn generate_keyboard_event(
+			&mut self,
+			keycode: i32,
+			keystring: &str,
+			type_: u32,
+		) -> zbus::fdo::Result<()> {
+			if keycode != 0 || type_ != KeySynthType::String as u32 {
+				return Err(zbus::fdo::Error::NotSupported("only strings are supported".into()));
+			}
+			self.typed.push(keystring.to_owned());
+			Ok(())
+		}
+	}
+
+	async fn typed(text: &str) -> Vec<String> {
+		let path = "/org/a11y/atspi/registry/deviceeventcontroller";
+		let (conn, controller): (_, DeviceEventControllerProxy) =
+			serve_mock(path, MockDeviceEventController::default()).await;
+		controller.generate_keyboard_event_str(text).await.unwrap();
+		let mock = conn
+			.object_server()
+			.interface::<_, MockDeviceEventController>(path)
+			.await
+			.unwrap();
+		let typed = std::mem::take(&mut mock.get_mut().await.typed);
+		typed
+	}
+
+	#[tokio::test]
+	async fn one_event_per_character() {
+		assert_eq!(typed("ab").await, ["a", "b"]);
+		assert!(typed("").await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn non_bmp_characters_are_sent_whole() {
+		assert_eq!(typed("é😀").await, ["é", "😀"]);
+	}
+}
@@ -10,10 +10,91 @@
 //! section of the zbus documentation.
 //!
 
-use crate::device_event_controller::DeviceEvent;
+use crate::device_event_controller::{DeviceEvent, EventType};
 
 #[zbus::proxy(interface = "org.a11y.atspi.DeviceEventListener", assume_defaults = true)]
 trait DeviceEventListener {
 	/// NotifyEvent method
 	fn notify_event(&self, event: &DeviceEvent<'_>) -> zbus::Result<bool>;
 }
+
+/// A key press or release, as delivered to a [`DeviceEventListenerProxy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyEvent<'a> {
+	/// Whether the key was pressed, as opposed to released.
+	pub pressed: bool,
+	/// The hardware keycode.
+	pub keycode: i32,
+	/// The X keysym the key produced.
+	pub keysym: i32,
+	/// The modifier mask active at the time of the event.
+	pub modifiers: i32,
+	/// The text the key produced, if any.
+	pub text: &'a str,
+	/// The time of the event, in milliseconds.
+	pub timestamp: i32,
+}
+
+impl<'a> From<&KeyEvent<'a>> for DeviceEvent<'a> {
+	fn from(event: &KeyEvent<'a>) -> Self {
+		DeviceEvent {
+			event_type: if event.pressed { EventType::KeyPressed } else { EventType::KeyReleased },
+			id: event.keysym,
+			hw_code: event.keycode,
+			modifiers: event.modifiers,
+			timestamp: event.timestamp,
+			event_string: event.text,
+			is_text: !event.text.is_empty(),
+		}
+	}
+}
+
+impl DeviceEventListenerProxy<'_> {
+	/// Notifies the listener of a key event.
+	///
+	/// Returns `true` if the listener consumed the event.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn notify_key_event(&self, event: &KeyEvent<'_>) -> zbus::Result<bool> {
+		self.notify_event(&DeviceEvent::from(event)).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::KeyEvent;
+	use crate::device_event_controller::{DeviceEvent, EventType};
+	use zbus::zvariant::{serialized::Context, to_bytes, Type, LE};
+
+	#[test]
+	fn key_event_encodes_as_device_event() {
+		let key = KeyEvent {
+			pressed: true,
+			keycode: 38,
+			keysym: 0x61,
+			modifiers: 1,
+			text: "a",
+			timestamp: 1234,
+		};
+		let event = DeviceEvent::from(&key);
+		assert_eq!(DeviceEvent::signature(), "(uiiiisb)");
+
+		let encoded = to_bytes(Context::new_dbus(LE, 0), &event).unwrap();
+		let (decoded, _) = encoded
+			.deserialize::<(u32, i32, i32, i32, i32, String, bool)>()
+			.unwrap();
+		assert_eq!(
+			decoded,
+			(EventType::KeyPressed as u32, 0x61, 38, 1, 1234, "a".to_owned(), true)
+		);
+	}
+
+	#[test]
+	fn released_key_without_text() {
+		let event = DeviceEvent::from(&KeyEvent::default());
+		assert_eq!(event.event_type, EventType::KeyReleased);
+		assert!(!event.is_text);
+	}
+}
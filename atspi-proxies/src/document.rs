@@ -11,10 +11,13 @@
 //!
 
 use crate::common::TextSelection;
+use std::collections::HashMap;
 
 #[zbus::proxy(interface = "org.a11y.atspi.Document", assume_defaults = true)]
 trait Document {
-	/// GetTextSelections method
+	/// The selections of text in the document, each of which may span several objects.
+	///
+	/// Returns an empty list if nothing is selected.
 	fn get_text_selections(&self) -> zbus::Result<Vec<TextSelection>>;
 
 	/// SetTextSelections method
@@ -23,17 +26,159 @@ trait Document {
 	/// GetAttributeValue method
 	fn get_attribute_value(&self, attributename: &str) -> zbus::Result<String>;
 
-	/// GetAttributes method
-	fn get_attributes(&self) -> zbus::Result<std::collections::HashMap<String, String>>;
+	/// All attributes of the document, e.g. `DocURL` or `MimeType`, by name.
+	fn get_attributes(&self) -> zbus::Result<HashMap<String, String>>;
 
 	/// GetLocale method
 	fn get_locale(&self) -> zbus::Result<String>;
 
-	/// CurrentPageNumber property
+	/// The number of the page currently shown, counting from 1, or -1 if the document does not support pages.
 	#[zbus(property)]
 	fn current_page_number(&self) -> zbus::Result<i32>;
 
-	/// PageCount property
+	/// The number of pages in the document, or -1 if the document does not support pages.
 	#[zbus(property)]
 	fn page_count(&self) -> zbus::Result<i32>;
 }
+
+impl DocumentProxy<'_> {
+	/// The value of the document attribute `key`.
+	///
+	/// Returns `None` if the document does not have the attribute,
+	/// which the interface cannot tell apart from an attribute with an empty value.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn attribute(&self, key: &str) -> zbus::Result<Option<String>> {
+		let value = self.get_attribute_value(key).await?;
+		Ok(Some(value).filter(|value| !value.is_empty()))
+	}
+
+	/// Replaces the selections of text in the document with `selections`.
+	///
+	/// Like [`set_text_selections`], but borrows the selections.
+	/// An empty slice asks the application to clear the selection.
+	/// Returns `true` if the application accepted the request.
+	///
+	/// # Errors
+	///
+	/// If the [`set_text_selections`] call fails.
+	///
+	/// [`set_text_selections`]: #method.set_text_selections
+	pub async fn set_text_selections_typed(
+		&self,
+		selections: &[TextSelection],
+	) -> zbus::Result<bool> {
+		self.set_text_selections(selections.to_vec()).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::DocumentProxy;
+	use crate::common::{ObjectRef, TextSelection};
+	use crate::serve_mock;
+	use std::collections::HashMap;
+
+	struct MockDocument {
+		attributes: HashMap<String, String>,
+		selections: Vec<TextSelection>,
+		current_page_number: i32,
+		page_count: i32,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Document")]
+	impl MockDocument {
+		fn get_attribute_value(&self, attributename: &str) -> String {
+			self.attributes.get(attributename).cloned().unwrap_or_default()
+		}
+
+		fn get_attributes(&self) -> HashMap<String, String> {
+			self.attributes.clone()
+		}
+
+		fn get_text_selections(&self) -> Vec<TextSelection> {
+			self.selections.clone()
+		}
+
+		fn set_text_selections(&mut self, selections: Vec<TextSelection>) -> bool {
+			self.selections = selections;
+			true
+		}
+
+		#[zbus(property)]
+		fn current_page_number(&self) -> i32 {
+			self.current_page_number
+		}
+
+		#[zbus(property)]
+		fn page_count(&self) -> i32 {
+			self.page_count
+		}
+	}
+
+	fn attributes() -> HashMap<String, String> {
+		HashMap::from([
+			("DocURL".to_owned(), "https://example.com/".to_owned()),
+			("MimeType".to_owned(), "text/html".to_owned()),
+		])
+	}
+
+	async fn mock_document() -> (zbus::Connection, DocumentProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/document";
+		serve_mock(
+			path,
+			MockDocument {
+				attributes: attributes(),
+				selections: Vec::new(),
+				current_page_number: 3,
+				page_count: 10,
+			},
+		)
+		.await
+	}
+
+	#[tokio::test]
+	async fn get_all_attributes() {
+		let (_conn, document) = mock_document().await;
+		assert_eq!(document.get_attributes().await.unwrap(), attributes());
+	}
+
+	#[tokio::test]
+	async fn single_attribute() {
+		let (_conn, document) = mock_document().await;
+		assert_eq!(document.attribute("MimeType").await.unwrap().as_deref(), Some("text/html"));
+		assert_eq!(document.attribute("Title").await.unwrap(), None);
+	}
+
+	fn paragraph(n: u32) -> ObjectRef {
+		ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/{n}").try_into().unwrap(),
+		}
+	}
+
+	#[tokio::test]
+	async fn set_and_get_text_selections() {
+		let (_conn, document) = mock_document().await;
+		assert!(document.get_text_selections().await.unwrap().is_empty());
+
+		let selections = [
+			TextSelection::new(paragraph(1), 5, paragraph(2), 3, true),
+			TextSelection::new(paragraph(3), 0, paragraph(3), 4, false),
+		];
+		assert!(document.set_text_selections_typed(&selections).await.unwrap());
+		assert_eq!(document.get_text_selections().await.unwrap(), selections);
+
+		assert!(document.set_text_selections_typed(&[]).await.unwrap());
+		assert!(document.get_text_selections().await.unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn page_three_of_ten() {
+		let (_conn, document) = mock_document().await;
+		assert_eq!(document.current_page_number().await.unwrap(), 3);
+		assert_eq!(document.page_count().await.unwrap(), 10);
+	}
+}
@@ -10,6 +10,8 @@
 //! section of the zbus documentation.
 //!
 
+use crate::text::TextProxy;
+
 #[zbus::proxy(interface = "org.a11y.atspi.EditableText", assume_defaults = true)]
 trait EditableText {
 	/// CopyText method
@@ -30,3 +32,112 @@ trait EditableText {
 	/// SetTextContents method
 	fn set_text_contents(&self, new_contents: &str) -> zbus::Result<bool>;
 }
+
+impl EditableTextProxy<'_> {
+	/// Replaces all of the text with `text`.
+	///
+	/// Returns `true` if the application accepted the change.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn replace_all(&self, text: &str) -> zbus::Result<bool> {
+		self.set_text_contents(text).await
+	}
+
+	/// Inserts `text` after the last character.
+	///
+	/// The current length is read through the object's `Text` interface,
+	/// which every object implementing `EditableText` also implements.
+	///
+	/// Returns `true` if the application accepted the change.
+	///
+	/// # Errors
+	///
+	/// - If any of the `DBus` calls fail.
+	/// - If `text` is longer than `i32::MAX` bytes.
+	pub async fn append(&self, text: &str) -> zbus::Result<bool> {
+		let length = i32::try_from(text.len())
+			.map_err(|_| zbus::Error::Failure("text is too long to insert".into()))?;
+		let proxy = self.inner();
+		let count = TextProxy::builder(proxy.connection())
+			.destination(proxy.destination().to_owned())?
+			.path(proxy.path().to_owned())?
+			.cache_properties(zbus::proxy::CacheProperties::No)
+			.build()
+			.await?
+			.character_count()
+			.await?;
+		self.insert_text(count, text, length).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::EditableTextProxy;
+	use crate::serve_mock;
+
+	/// Records the changes requested, without applying them.
+	#[derive(Default)]
+	struct MockEditableText {
+		inserted: Vec<(i32, String, i32)>,
+		contents: Option<String>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.EditableText")]
+	impl MockEditableText {
+		fn insert_text(&mut self, position: i32, text: &str, length: i32) -> bool {
+			self.inserted.push((position, text.to_owned(), length));
+			true
+		}
+
+		fn set_text_contents(&mut self, new_contents: &str) -> bool {
+			self.contents = Some(new_contents.to_owned());
+			true
+		}
+	}
+
+	struct MockText {
+		text: &'static str,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Text")]
+	impl MockText {
+		#[zbus(property)]
+		fn character_count(&self) -> i32 {
+			self.text.chars().count().try_into().unwrap()
+		}
+	}
+
+	const PATH: &str = "/org/a11y/atspi/accessible/entry";
+
+	async fn mock_entry(text: &'static str) -> (zbus::Connection, EditableTextProxy<'static>) {
+		let (conn, proxy) = serve_mock(PATH, MockEditableText::default()).await;
+		conn.object_server().at(PATH, MockText { text }).await.unwrap();
+		(conn, proxy)
+	}
+
+	async fn recorded(conn: &zbus::Connection) -> (Vec<(i32, String, i32)>, Option<String>) {
+		let mock = conn
+			.object_server()
+			.interface::<_, MockEditableText>(PATH)
+			.await
+			.unwrap();
+		let mock = mock.get().await;
+		(mock.inserted.clone(), mock.contents.clone())
+	}
+
+	#[tokio::test]
+	async fn append_inserts_at_end() {
+		let (conn, entry) = mock_entry("text").await;
+		assert!(entry.append("ing").await.unwrap());
+		assert_eq!(recorded(&conn).await, (vec![(4, "ing".to_owned(), 3)], None));
+	}
+
+	#[tokio::test]
+	async fn replace_all_sets_contents() {
+		let (conn, entry) = mock_entry("text").await;
+		assert!(entry.replace_all("new text").await.unwrap());
+		assert_eq!(recorded(&conn).await, (vec![], Some("new text".to_owned())));
+	}
+}
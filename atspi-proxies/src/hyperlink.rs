@@ -18,6 +18,7 @@ trait Hyperlink {
 	fn get_object(&self, i: i32) -> zbus::Result<ObjectRef>;
 
 	/// GetURI method
+	#[zbus(name = "GetURI")]
 	fn get_uri(&self, i: i32) -> zbus::Result<String>;
 
 	/// IsValid method
@@ -28,10 +29,106 @@ trait Hyperlink {
 	fn end_index(&self) -> zbus::Result<i32>;
 
 	/// NAnchors property
-	#[zbus(property)]
+	#[zbus(property, name = "NAnchors")]
 	fn nanchors(&self) -> zbus::Result<i16>;
 
 	/// StartIndex property
 	#[zbus(property)]
 	fn start_index(&self) -> zbus::Result<i32>;
 }
+
+impl HyperlinkProxy<'_> {
+	/// The URI and object of each of the link's anchors, in anchor index order.
+	///
+	/// Most links have a single anchor; image maps, for instance, may have several.
+	/// A negative anchor count, as reported by some backends for invalid links, is treated as zero.
+	///
+	/// # Errors
+	///
+	/// If any of the `DBus` calls fail.
+	pub async fn anchors(&self) -> zbus::Result<Vec<(String, ObjectRef)>> {
+		let count = self.nanchors().await?.max(0);
+		let mut anchors = Vec::with_capacity(count.unsigned_abs().into());
+		for index in 0..i32::from(count) {
+			anchors.push((self.get_uri(index).await?, self.get_object(index).await?));
+		}
+		Ok(anchors)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::HyperlinkProxy;
+	use crate::common::ObjectRef;
+	use crate::serve_mock;
+
+	struct MockHyperlink {
+		anchors: Vec<(String, ObjectRef)>,
+		/// The anchor count to report, which may be inconsistent with `anchors`.
+		nanchors: i16,
+	}
+
+	impl MockHyperlink {
+		fn anchor(&self, i: i32) -> zbus::fdo::Result<&(String, ObjectRef)> {
+			usize::try_from(i)
+				.ok()
+				.and_then(|i| self.anchors.get(i))
+				.ok_or_else(|| zbus::fdo::Error::InvalidArgs("no such anchor".into()))
+		}
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Hyperlink")]
+	impl MockHyperlink {
+		fn get_object(&self, i: i32) -> zbus::fdo::Result<ObjectRef> {
+			Ok(self.anchor(i)?.1.clone())
+		}
+
+		#[zbus(name = "GetURI")]
+		fn get_uri(&self, i: i32) -> zbus::fdo::Result<String> {
+			Ok(self.anchor(i)?.0.clone())
+		}
+
+		#[zbus(property, name = "NAnchors")]
+		fn nanchors(&self) -> i16 {
+			self.nanchors
+		}
+	}
+
+	fn object(name: &str) -> ObjectRef {
+		ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/{name}").try_into().unwrap(),
+		}
+	}
+
+	async fn mock_hyperlink(
+		anchors: &[(&str, &str)],
+		nanchors: i16,
+	) -> (zbus::Connection, HyperlinkProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/link";
+		let anchors = anchors
+			.iter()
+			.map(|(uri, name)| ((*uri).to_owned(), object(name)))
+			.collect();
+		serve_mock(path, MockHyperlink { anchors, nanchors }).await
+	}
+
+	#[tokio::test]
+	async fn anchors_combine_uri_and_object() {
+		let anchors = [("https://example.com/", "north"), ("https://example.org/", "south")];
+		let (_conn, link) = mock_hyperlink(&anchors, 2).await;
+		assert_eq!(
+			link.anchors().await.unwrap(),
+			[
+				("https://example.com/".to_owned(), object("north")),
+				("https://example.org/".to_owned(), object("south")),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn negative_anchor_count_means_no_anchors() {
+		let (_conn, link) = mock_hyperlink(&[("https://example.com/", "north")], -1).await;
+		assert!(link.anchors().await.unwrap().is_empty());
+	}
+}
@@ -11,6 +11,8 @@
 //!
 
 use crate::common::ObjectRef;
+use crate::hyperlink::HyperlinkProxy;
+use crate::proxy_ext::ObjectRefProxyExt;
 
 #[zbus::proxy(interface = "org.a11y.atspi.Hypertext", assume_defaults = true)]
 trait Hypertext {
@@ -21,5 +23,83 @@ trait Hypertext {
 	fn get_link_index(&self, character_index: i32) -> zbus::Result<i32>;
 
 	/// GetNLinks method
+	#[zbus(name = "GetNLinks")]
 	fn get_nlinks(&self) -> zbus::Result<i32>;
 }
+
+impl HypertextProxy<'_> {
+	/// Proxies for all hyperlinks in the text, in link index order.
+	///
+	/// Returns an empty `Vec` if the text has no links.
+	///
+	/// # Errors
+	///
+	/// If any of the `DBus` calls fail, or a proxy cannot be built from a link's [`ObjectRef`].
+	pub async fn links(&self) -> zbus::Result<Vec<HyperlinkProxy<'static>>> {
+		let conn = self.inner().connection();
+		let count = self.get_nlinks().await?;
+		let mut links = Vec::with_capacity(usize::try_from(count).unwrap_or(0));
+		for index in 0..count {
+			let link = self.get_link(index).await?;
+			links.push(link.into_hyperlink(conn).await?);
+		}
+		Ok(links)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::HypertextProxy;
+	use crate::common::ObjectRef;
+	use crate::serve_mock;
+
+	struct MockHypertext {
+		links: Vec<ObjectRef>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Hypertext")]
+	impl MockHypertext {
+		fn get_link(&self, link_index: i32) -> zbus::fdo::Result<ObjectRef> {
+			usize::try_from(link_index)
+				.ok()
+				.and_then(|index| self.links.get(index))
+				.cloned()
+				.ok_or_else(|| zbus::fdo::Error::InvalidArgs("no such link".into()))
+		}
+
+		#[zbus(name = "GetNLinks")]
+		fn get_nlinks(&self) -> i32 {
+			self.links.len().try_into().unwrap()
+		}
+	}
+
+	fn link_path(index: usize) -> String {
+		format!("/org/a11y/atspi/accessible/link{index}")
+	}
+
+	async fn mock_hypertext(count: usize) -> (zbus::Connection, HypertextProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/paragraph";
+		let links = (0..count).map(|index| ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: link_path(index).try_into().unwrap(),
+		});
+		serve_mock(path, MockHypertext { links: links.collect() }).await
+	}
+
+	#[tokio::test]
+	async fn links_are_built_in_order() {
+		let (_conn, hypertext) = mock_hypertext(3).await;
+		let links = hypertext.links().await.unwrap();
+		assert_eq!(links.len(), 3);
+		for (index, link) in links.iter().enumerate() {
+			assert_eq!(link.inner().path().as_str(), link_path(index));
+			assert_eq!(link.inner().destination().as_str(), ":1.42");
+		}
+	}
+
+	#[tokio::test]
+	async fn no_links() {
+		let (_conn, hypertext) = mock_hypertext(0).await;
+		assert!(hypertext.links().await.unwrap().is_empty());
+	}
+}
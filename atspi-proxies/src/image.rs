@@ -10,6 +10,7 @@
 //! section of the zbus documentation.
 //!
 
+use crate::common::Rect;
 use crate::CoordType;
 
 #[zbus::proxy(interface = "org.a11y.atspi.Image", assume_defaults = true)]
@@ -31,3 +32,56 @@ trait Image {
 	#[zbus(property)]
 	fn image_locale(&self) -> zbus::Result<String>;
 }
+
+impl ImageProxy<'_> {
+	/// The position and size of the image, with the position relative to `coord_type`.
+	///
+	/// Only the position depends on the coordinate frame; the size is the same in all frames.
+	///
+	/// # Errors
+	///
+	/// If either of the `DBus` calls fails.
+	pub async fn image_rect(&self, coord_type: CoordType) -> zbus::Result<Rect> {
+		let (x, y) = self.get_image_position(coord_type).await?;
+		let (width, height) = self.get_image_size().await?;
+		Ok(Rect::new(x, y, width, height))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ImageProxy;
+	use crate::common::{CoordType, Rect};
+	use crate::serve_mock;
+
+	/// An image inside a window at (100, 50) on screen.
+	struct MockImage {
+		window: (i32, i32),
+		position: (i32, i32),
+		size: (i32, i32),
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Image")]
+	impl MockImage {
+		fn get_image_position(&self, coord_type: u32) -> (i32, i32) {
+			if coord_type == CoordType::Screen as u32 {
+				(self.position.0 + self.window.0, self.position.1 + self.window.1)
+			} else {
+				self.position
+			}
+		}
+
+		fn get_image_size(&self) -> (i32, i32) {
+			self.size
+		}
+	}
+
+	#[tokio::test]
+	async fn image_rect_combines_position_and_size() {
+		let path = "/org/a11y/atspi/accessible/image";
+		let mock = MockImage { window: (100, 50), position: (10, 20), size: (64, 48) };
+		let (_conn, image): (_, ImageProxy) = serve_mock(path, mock).await;
+		assert_eq!(image.image_rect(CoordType::Window).await.unwrap(), Rect::new(10, 20, 64, 48));
+		assert_eq!(image.image_rect(CoordType::Screen).await.unwrap(), Rect::new(110, 70, 64, 48));
+	}
+}
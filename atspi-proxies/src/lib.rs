@@ -32,4 +32,35 @@ pub mod socket;
 pub mod table;
 pub mod table_cell;
 pub mod text;
+pub mod traversal_helper;
 pub mod value;
+
+/// Serves `iface` at `path` on a new session bus connection, and returns the connection
+/// along with an uncached proxy for the served object.
+///
+/// Keep the connection alive for as long as the proxy is used: it is what serves the mock.
+#[cfg(test)]
+pub(crate) async fn serve_mock<I, P>(path: &str, iface: I) -> (zbus::Connection, P)
+where
+	I: zbus::object_server::Interface,
+	P: From<zbus::Proxy<'static>> + zbus::proxy::ProxyDefault,
+{
+	let conn = zbus::connection::Builder::session()
+		.unwrap()
+		.serve_at(path, iface)
+		.unwrap()
+		.build()
+		.await
+		.unwrap();
+	// Uncached, so that tests may change the mock after the proxy is built.
+	let proxy = zbus::proxy::Builder::new(&conn)
+		.destination(conn.unique_name().unwrap().to_owned())
+		.unwrap()
+		.path(path.to_owned())
+		.unwrap()
+		.cache_properties(zbus::proxy::CacheProperties::No)
+		.build()
+		.await
+		.unwrap();
+	(conn, proxy)
+}
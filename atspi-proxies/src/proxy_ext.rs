@@ -5,7 +5,8 @@ use crate::{
 	hypertext::HypertextProxy, image::ImageProxy, selection::SelectionProxy, table::TableProxy,
 	table_cell::TableCellProxy, text::TextProxy, value::ValueProxy, AtspiError,
 };
-use atspi_common::{Interface, InterfaceSet, Result};
+use atspi_common::{Interface, InterfaceSet, ObjectRef, Result};
+use std::future::Future;
 
 /// Easily acquire the other interface proxies an object may have.
 ///
@@ -315,3 +316,317 @@ impl<'a> Proxies<'a> {
 		}
 	}
 }
+
+/// Build any interface proxy for an [`ObjectRef`], e.g. one received in an event or a relation.
+///
+/// The proxies use the reference's name as destination and its path, and do not cache properties.
+///
+/// Whether the object implements the interface is not checked:
+/// a proxy for an interface the object lacks builds fine, but its calls fail.
+/// Use [`ProxyExt::proxies`] on an [`AccessibleProxy`] to check the implemented interfaces first.
+pub trait ObjectRefProxyExt {
+	/// Returns an [`AccessibleProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_accessible(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<AccessibleProxy<'static>>> + Send;
+
+	/// Returns an [`ActionProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_action(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<ActionProxy<'static>>> + Send;
+
+	/// Returns an [`ApplicationProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_application(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<ApplicationProxy<'static>>> + Send;
+
+	/// Returns a [`CacheProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_cache(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<CacheProxy<'static>>> + Send;
+
+	/// Returns a [`CollectionProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_collection(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<CollectionProxy<'static>>> + Send;
+
+	/// Returns a [`ComponentProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_component(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<ComponentProxy<'static>>> + Send;
+
+	/// Returns a [`DocumentProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_document(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<DocumentProxy<'static>>> + Send;
+
+	/// Returns an [`EditableTextProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_editable_text(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<EditableTextProxy<'static>>> + Send;
+
+	/// Returns a [`HyperlinkProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_hyperlink(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<HyperlinkProxy<'static>>> + Send;
+
+	/// Returns a [`HypertextProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_hypertext(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<HypertextProxy<'static>>> + Send;
+
+	/// Returns an [`ImageProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_image(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<ImageProxy<'static>>> + Send;
+
+	/// Returns a [`SelectionProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_selection(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<SelectionProxy<'static>>> + Send;
+
+	/// Returns a [`TableProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_table(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<TableProxy<'static>>> + Send;
+
+	/// Returns a [`TableCellProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_table_cell(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<TableCellProxy<'static>>> + Send;
+
+	/// Returns a [`TextProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_text(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<TextProxy<'static>>> + Send;
+
+	/// Returns a [`ValueProxy`] for the object.
+	///
+	/// # Errors
+	///
+	/// If the proxy cannot be built.
+	fn into_value(
+		self,
+		conn: &zbus::Connection,
+	) -> impl Future<Output = zbus::Result<ValueProxy<'static>>> + Send;
+}
+
+impl ObjectRefProxyExt for ObjectRef {
+	async fn into_accessible(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<AccessibleProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_action(self, conn: &zbus::Connection) -> zbus::Result<ActionProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_application(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<ApplicationProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_cache(self, conn: &zbus::Connection) -> zbus::Result<CacheProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_collection(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<CollectionProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_component(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<ComponentProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_document(self, conn: &zbus::Connection) -> zbus::Result<DocumentProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_editable_text(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<EditableTextProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_hyperlink(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<HyperlinkProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_hypertext(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<HypertextProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_image(self, conn: &zbus::Connection) -> zbus::Result<ImageProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_selection(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<SelectionProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_table(self, conn: &zbus::Connection) -> zbus::Result<TableProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_table_cell(
+		self,
+		conn: &zbus::Connection,
+	) -> zbus::Result<TableCellProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_text(self, conn: &zbus::Connection) -> zbus::Result<TextProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+
+	async fn into_value(self, conn: &zbus::Connection) -> zbus::Result<ValueProxy<'static>> {
+		build_proxy(self, conn).await
+	}
+}
+
+/// Builds a proxy of any interface for `obj`, without caching properties.
+///
+/// All of the crate's conversions from an [`ObjectRef`] to a proxy go through this.
+pub(crate) async fn build_proxy<T>(obj: ObjectRef, conn: &zbus::Connection) -> zbus::Result<T>
+where
+	T: From<zbus::Proxy<'static>> + zbus::proxy::ProxyDefault,
+{
+	zbus::proxy::Builder::<T>::new(conn)
+		.destination(obj.name)?
+		.path(obj.path)?
+		.cache_properties(zbus::proxy::CacheProperties::No)
+		.build()
+		.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ObjectRefProxyExt;
+	use atspi_common::{ObjectRef, Role};
+
+	struct MockAccessible {
+		role: Role,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Accessible")]
+	impl MockAccessible {
+		fn get_role(&self) -> Role {
+			self.role
+		}
+	}
+
+	#[tokio::test]
+	async fn accessible_from_object_ref() {
+		let path = "/org/a11y/atspi/accessible/button";
+		let conn = zbus::connection::Builder::session()
+			.unwrap()
+			.serve_at(path, MockAccessible { role: Role::Button })
+			.unwrap()
+			.build()
+			.await
+			.unwrap();
+		let obj =
+			ObjectRef { name: conn.unique_name().unwrap().clone(), path: path.try_into().unwrap() };
+
+		let button = obj.clone().into_accessible(&conn).await.unwrap();
+		assert_eq!(button.inner().destination().as_str(), obj.name.as_str());
+		assert_eq!(button.inner().path().as_str(), path);
+		assert_eq!(button.get_role().await.unwrap(), Role::Button);
+		assert!(obj.into_text(&conn).await.unwrap().character_count().await.is_err());
+	}
+}
@@ -18,13 +18,71 @@ use zbus::names::OwnedBusName;
 	default_path = "/org/a11y/atspi/registry"
 )]
 trait Registry {
-	/// DeregisterEvent method
+	/// Stops the registry from forwarding events of the given kind to this connection.
+	///
+	/// The counterpart of [`register_event`], taking the same event string,
+	/// e.g. `"Object:StateChanged"`.
+	///
+	/// [`register_event`]: #method.register_event
 	fn deregister_event(&self, event: &str) -> zbus::Result<()>;
 
 	/// GetRegisteredEvents method
 	#[zbus(name = "GetRegisteredEvents")]
 	fn registered_events(&self) -> zbus::Result<Vec<(OwnedBusName, String)>>;
 
-	/// RegisterEvent method
+	/// Asks the registry to forward events of the given kind to this connection.
+	///
+	/// `event` is an interface, optionally followed by a member and detail, e.g. `"Object:"`,
+	/// `"Object:StateChanged"` or `"Object:StateChanged:focused"`.
+	/// Undo this with [`deregister_event`].
+	///
+	/// [`deregister_event`]: #method.deregister_event
 	fn register_event(&self, event: &str) -> zbus::Result<()>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::RegistryProxy;
+	use crate::serve_mock;
+	use zbus::names::{BusName, OwnedBusName};
+
+	#[derive(Default)]
+	struct MockRegistry {
+		events: Vec<(OwnedBusName, String)>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Registry")]
+	impl MockRegistry {
+		/// Records the event for the connection serving the mock, which is also the caller in the tests.
+		fn register_event(&mut self, event: String, #[zbus(connection)] conn: &zbus::Connection) {
+			let listener = BusName::from(conn.unique_name().unwrap().to_owned());
+			self.events.push((listener.into(), event));
+		}
+
+		fn deregister_event(&mut self, event: &str) {
+			self.events.retain(|(_, registered)| registered != event);
+		}
+
+		fn get_registered_events(&self) -> Vec<(OwnedBusName, String)> {
+			self.events.clone()
+		}
+	}
+
+	#[tokio::test]
+	async fn register_and_deregister_round_trip() {
+		let (conn, registry): (_, RegistryProxy) =
+			serve_mock("/org/a11y/atspi/registry", MockRegistry::default()).await;
+		let name = BusName::from(conn.unique_name().unwrap().to_owned());
+
+		registry.register_event("Object:StateChanged").await.unwrap();
+		registry.register_event("Window:").await.unwrap();
+		registry.deregister_event("Object:StateChanged").await.unwrap();
+		assert_eq!(
+			registry.registered_events().await.unwrap(),
+			[(OwnedBusName::from(name), "Window:".to_owned())]
+		);
+
+		registry.deregister_event("Window:").await.unwrap();
+		assert!(registry.registered_events().await.unwrap().is_empty());
+	}
+}
@@ -36,6 +36,76 @@ trait Selection {
 	fn select_child(&self, child_index: i32) -> zbus::Result<bool>;
 
 	/// NSelectedChildren property
-	#[zbus(property)]
+	#[zbus(property, name = "NSelectedChildren")]
 	fn nselected_children(&self) -> zbus::Result<i32>;
 }
+
+impl SelectionProxy<'_> {
+	/// All selected children, in the order the object reports them.
+	///
+	/// Returns an empty `Vec` if nothing is selected.
+	///
+	/// # Errors
+	///
+	/// If any of the `DBus` calls fail.
+	pub async fn selected_children(&self) -> zbus::Result<Vec<ObjectRef>> {
+		let count = self.nselected_children().await?;
+		let mut children = Vec::with_capacity(usize::try_from(count).unwrap_or(0));
+		for index in 0..count {
+			children.push(self.get_selected_child(index).await?);
+		}
+		Ok(children)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SelectionProxy;
+	use crate::common::ObjectRef;
+	use crate::serve_mock;
+
+	struct MockSelection {
+		selected: Vec<ObjectRef>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Selection")]
+	impl MockSelection {
+		fn get_selected_child(&self, selected_child_index: i32) -> zbus::fdo::Result<ObjectRef> {
+			usize::try_from(selected_child_index)
+				.ok()
+				.and_then(|index| self.selected.get(index))
+				.cloned()
+				.ok_or_else(|| zbus::fdo::Error::InvalidArgs("no such selected child".into()))
+		}
+
+		#[zbus(property, name = "NSelectedChildren")]
+		fn nselected_children(&self) -> i32 {
+			self.selected.len().try_into().unwrap()
+		}
+	}
+
+	fn child(index: usize) -> ObjectRef {
+		ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/item{index}").try_into().unwrap(),
+		}
+	}
+
+	async fn mock_selection(selected: &[usize]) -> (zbus::Connection, SelectionProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/list";
+		let selected = selected.iter().map(|&index| child(index)).collect();
+		serve_mock(path, MockSelection { selected }).await
+	}
+
+	#[tokio::test]
+	async fn selected_children_in_order() {
+		let (_conn, selection) = mock_selection(&[1, 3]).await;
+		assert_eq!(selection.selected_children().await.unwrap(), [child(1), child(3)]);
+	}
+
+	#[tokio::test]
+	async fn no_selected_children() {
+		let (_conn, selection) = mock_selection(&[]).await;
+		assert!(selection.selected_children().await.unwrap().is_empty());
+	}
+}
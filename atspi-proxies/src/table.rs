@@ -82,22 +82,126 @@ trait Table {
 	fn caption(&self) -> zbus::Result<ObjectRef>;
 
 	/// NColumns property
-	#[zbus(property)]
+	#[zbus(property, name = "NColumns")]
 	fn ncolumns(&self) -> zbus::Result<i32>;
 
 	/// NRows property
-	#[zbus(property)]
+	#[zbus(property, name = "NRows")]
 	fn nrows(&self) -> zbus::Result<i32>;
 
 	/// NSelectedColumns property
-	#[zbus(property)]
+	#[zbus(property, name = "NSelectedColumns")]
 	fn nselected_columns(&self) -> zbus::Result<i32>;
 
 	/// NSelectedRows property
-	#[zbus(property)]
+	#[zbus(property, name = "NSelectedRows")]
 	fn nselected_rows(&self) -> zbus::Result<i32>;
 
 	/// Summary property
 	#[zbus(property)]
 	fn summary(&self) -> zbus::Result<ObjectRef>;
 }
+
+impl TableProxy<'_> {
+	/// The accessible cell at `row`, `column`.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails, e.g. because either index is out of range.
+	pub async fn cell_at(&self, row: i32, column: i32) -> zbus::Result<ObjectRef> {
+		self.get_accessible_at(row, column).await
+	}
+
+	/// The number of rows in the table.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn row_count(&self) -> zbus::Result<i32> {
+		self.nrows().await
+	}
+
+	/// The number of columns in the table.
+	///
+	/// # Errors
+	///
+	/// If the `DBus` call fails.
+	pub async fn column_count(&self) -> zbus::Result<i32> {
+		self.ncolumns().await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::TableProxy;
+	use crate::common::ObjectRef;
+	use crate::serve_mock;
+
+	/// A table whose cells are laid out row by row.
+	struct MockTable {
+		cells: Vec<Vec<ObjectRef>>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Table")]
+	impl MockTable {
+		fn get_accessible_at(&self, row: i32, column: i32) -> zbus::fdo::Result<ObjectRef> {
+			usize::try_from(row)
+				.ok()
+				.and_then(|row| self.cells.get(row))
+				.zip(usize::try_from(column).ok())
+				.and_then(|(cells, column)| cells.get(column))
+				.cloned()
+				.ok_or_else(|| zbus::fdo::Error::InvalidArgs(format!("no cell at {row}, {column}")))
+		}
+
+		#[zbus(property, name = "NRows")]
+		fn nrows(&self) -> i32 {
+			self.cells.len().try_into().unwrap()
+		}
+
+		#[zbus(property, name = "NColumns")]
+		fn ncolumns(&self) -> i32 {
+			self.cells.first().map_or(0, |row| row.len().try_into().unwrap())
+		}
+	}
+
+	fn cell(row: usize, column: usize) -> ObjectRef {
+		ObjectRef {
+			name: ":1.42".try_into().unwrap(),
+			path: format!("/org/a11y/atspi/accessible/cell_{row}_{column}")
+				.try_into()
+				.unwrap(),
+		}
+	}
+
+	async fn mock_table() -> (zbus::Connection, TableProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/table";
+		let cells = (0..2).map(|row| (0..2).map(|column| cell(row, column)).collect());
+		serve_mock(path, MockTable { cells: cells.collect() }).await
+	}
+
+	#[tokio::test]
+	async fn cells_of_2x2_table() {
+		let (_conn, table) = mock_table().await;
+		assert_eq!(table.row_count().await.unwrap(), 2);
+		assert_eq!(table.column_count().await.unwrap(), 2);
+		for row in 0..2 {
+			for column in 0..2 {
+				assert_eq!(
+					table
+						.cell_at(row.try_into().unwrap(), column.try_into().unwrap())
+						.await
+						.unwrap(),
+					cell(row, column)
+				);
+			}
+		}
+	}
+
+	#[tokio::test]
+	async fn out_of_range_cell_is_an_error() {
+		let (_conn, table) = mock_table().await;
+		assert!(table.cell_at(2, 0).await.is_err());
+		assert!(table.cell_at(0, -1).await.is_err());
+	}
+}
@@ -28,6 +28,10 @@ trait TableCell {
 	fn column_span(&self) -> zbus::Result<i32>;
 
 	/// Position property
+	///
+	/// The `(row, column)` of the cell in its table, both zero-based as in `ATK`.
+	/// This reads both coordinates in one call; use [`TableCellProxy::get_row_column_span`]
+	/// to also read the spans.
 	#[zbus(property)]
 	fn position(&self) -> zbus::Result<(i32, i32)>;
 
@@ -39,3 +43,29 @@ trait TableCell {
 	#[zbus(property)]
 	fn table(&self) -> zbus::Result<ObjectRef>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::TableCellProxy;
+	use crate::serve_mock;
+
+	struct MockTableCell {
+		position: (i32, i32),
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.TableCell")]
+	impl MockTableCell {
+		#[zbus(property)]
+		fn position(&self) -> (i32, i32) {
+			self.position
+		}
+	}
+
+	#[tokio::test]
+	async fn position_of_cell() {
+		let path = "/org/a11y/atspi/accessible/cell";
+		let (_conn, cell): (_, TableCellProxy) =
+			serve_mock(path, MockTableCell { position: (3, 5) }).await;
+		assert_eq!(cell.position().await.unwrap(), (3, 5));
+	}
+}
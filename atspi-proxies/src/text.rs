@@ -12,14 +12,33 @@
 #![allow(clippy::too_many_arguments)]
 // this is to silence clippy due to zbus expanding parameter expressions
 
-use crate::common::{ClipType, CoordType, Granularity};
+use crate::common::{ClipType, CoordType, Granularity, Rect};
+use crate::AtspiError;
+use futures_lite::stream::{self, Stream};
+
+/// A range of text, as returned by [`TextProxy::bounded_ranges`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextRange {
+	/// The offset of the first character in the range.
+	pub start: i32,
+	/// The offset after the last character in the range.
+	pub end: i32,
+	/// The text in the range.
+	pub content: String,
+}
 
 #[zbus::proxy(interface = "org.a11y.atspi.Text", assume_defaults = true)]
 trait Text {
 	/// AddSelection method
 	fn add_selection(&self, start_offset: i32, end_offset: i32) -> zbus::Result<bool>;
 
-	/// GetAttributeRun method
+	/// Returns the text attributes at `offset`, e.g. `"weight"`, and the run of characters sharing them,
+	/// as `(attributes, run_start, run_end)` with `run_end` exclusive.
+	///
+	/// If `include_defaults` is `true`, attributes the text inherits from the default attributes
+	/// are included, as with [`get_default_attributes`]; otherwise only attributes set on the run itself are.
+	///
+	/// [`get_default_attributes`]: #method.get_default_attributes
 	fn get_attribute_run(
 		&self,
 		offset: i32,
@@ -64,9 +83,13 @@ trait Text {
 	fn get_default_attributes(&self) -> zbus::Result<std::collections::HashMap<String, String>>;
 
 	/// GetNSelections method
+	#[zbus(name = "GetNSelections")]
 	fn get_nselections(&self) -> zbus::Result<i32>;
 
-	/// GetOffsetAtPoint method
+	/// Returns the offset of the character whose glyph contains the point (`x`, `y`),
+	/// the inverse of [`TextProxy::character_extents`].
+	///
+	/// Returns `-1` if the point is not within any glyph.
 	fn get_offset_at_point(&self, x: i32, y: i32, coord_type: CoordType) -> zbus::Result<i32>;
 
 	/// GetRangeExtents method
@@ -120,7 +143,10 @@ trait Text {
 		y: i32,
 	) -> zbus::Result<bool>;
 
-	/// SetCaretOffset method
+	/// Request the caret to move to `offset`.
+	///
+	/// Returns `true` if the application moved the caret.
+	/// See [`TextProxy::set_caret_offset_clamped`] to keep `offset` in range.
 	fn set_caret_offset(&self, offset: i32) -> zbus::Result<bool>;
 
 	/// SetSelection method
@@ -131,7 +157,7 @@ trait Text {
 		end_offset: i32,
 	) -> zbus::Result<bool>;
 
-	/// CaretOffset property
+	/// The offset of the character after the caret, or -1 if the object has no caret.
 	#[zbus(property)]
 	fn caret_offset(&self) -> zbus::Result<i32>;
 
@@ -139,3 +165,614 @@ trait Text {
 	#[zbus(property)]
 	fn character_count(&self) -> zbus::Result<i32>;
 }
+
+impl TextProxy<'_> {
+	/// Returns the ranges of text whose glyphs lie within `area`, typically one per line.
+	///
+	/// The clip types decide whether glyphs only partially within `area`, horizontally or vertically,
+	/// are included.
+	///
+	/// # Errors
+	///
+	/// If the underlying [`get_bounded_ranges`] call fails.
+	///
+	/// [`get_bounded_ranges`]: #method.get_bounded_ranges
+	pub async fn bounded_ranges(
+		&self,
+		area: Rect,
+		coord: CoordType,
+		x_clip: ClipType,
+		y_clip: ClipType,
+	) -> zbus::Result<Vec<TextRange>> {
+		let ranges = self
+			.get_bounded_ranges(area.x, area.y, area.width, area.height, coord, x_clip, y_clip)
+			.await?;
+		Ok(ranges
+			.into_iter()
+			.map(|(start, end, content, _)| TextRange { start, end, content })
+			.collect())
+	}
+
+	/// Returns the span of character offsets, `(start, end)` with `end` exclusive,
+	/// from the first to the last character whose glyph intersects the `viewport`.
+	///
+	/// This is useful to present only the text that is currently on screen,
+	/// e.g. on a braille display.
+	///
+	/// Partially visible glyphs at the edges of the viewport are included.
+	/// The span is contiguous, so it may include hidden text in between,
+	/// e.g. the end of a line which runs past the right edge of the viewport.
+	/// For the visible text of each line, use [`bounded_ranges`] instead.
+	/// If no text is visible, the empty range `(0, 0)` is returned.
+	///
+	/// # Errors
+	///
+	/// If the underlying [`get_bounded_ranges`] call fails.
+	///
+	/// [`bounded_ranges`]: #method.bounded_ranges
+	/// [`get_bounded_ranges`]: #method.get_bounded_ranges
+	pub async fn visible_range(
+		&self,
+		viewport: Rect,
+		coord: CoordType,
+	) -> Result<(i32, i32), AtspiError> {
+		let ranges = self
+			.get_bounded_ranges(
+				viewport.x,
+				viewport.y,
+				viewport.width,
+				viewport.height,
+				coord,
+				ClipType::Neither,
+				ClipType::Neither,
+			)
+			.await?;
+		let start = ranges.iter().map(|(start, ..)| *start).min();
+		let end = ranges.iter().map(|(_, end, ..)| *end).max();
+		Ok(start.zip(end).unwrap_or((0, 0)))
+	}
+
+	/// Returns the area of the character at `offset`, e.g. to draw the caret in front of it.
+	///
+	/// The caret may also be at the end of the text, where there is no character,
+	/// so toolkits report an empty area. For `offset` equal to the character count,
+	/// this returns an empty area at the right edge of the last character instead.
+	/// The character count is only fetched when the toolkit reports an empty area.
+	///
+	/// # Errors
+	///
+	/// If the [`get_character_extents`] or [`character_count`] call fails.
+	///
+	/// [`character_count`]: #method.character_count
+	/// [`get_character_extents`]: #method.get_character_extents
+	pub async fn character_extents(&self, offset: i32, coord: CoordType) -> zbus::Result<Rect> {
+		let extents = Rect::from(self.get_character_extents(offset, coord).await?);
+		if extents.width != 0 || extents.height != 0 {
+			return Ok(extents);
+		}
+		let count = self.character_count().await?;
+		if offset != count || count == 0 {
+			return Ok(extents);
+		}
+		let last = Rect::from(self.get_character_extents(count - 1, coord).await?);
+		Ok(Rect::new(last.x + last.width, last.y, 0, last.height))
+	}
+
+	/// Returns all of the text, from the first to the last character.
+	///
+	/// Empty text is returned without fetching it.
+	///
+	/// # Errors
+	///
+	/// If the [`character_count`] or [`get_text`] call fails.
+	///
+	/// [`character_count`]: #method.character_count
+	/// [`get_text`]: #method.get_text
+	pub async fn get_whole_text(&self) -> zbus::Result<String> {
+		let count = self.character_count().await?;
+		if count <= 0 {
+			return Ok(String::new());
+		}
+		self.get_text(0, count).await
+	}
+
+	/// Returns the offset of the next `granularity` boundary after `offset`,
+	/// i.e. the end of the segment containing `offset`.
+	///
+	/// The result lies between `offset` and the [`character_count`], inclusive,
+	/// so it can be used to move the caret without stepping out of range.
+	///
+	/// # Errors
+	///
+	/// If the [`character_count`] or [`get_string_at_offset`] call fails.
+	///
+	/// [`character_count`]: #method.character_count
+	/// [`get_string_at_offset`]: #method.get_string_at_offset
+	pub async fn next_boundary(&self, offset: i32, granularity: Granularity) -> zbus::Result<i32> {
+		let count = self.character_count().await?.max(0);
+		let offset = offset.clamp(0, count);
+		if offset == count {
+			return Ok(count);
+		}
+		let (_, _, end) = self.get_string_at_offset(offset, granularity).await?;
+		Ok(end.clamp(offset, count))
+	}
+
+	/// Returns the offset of the previous `granularity` boundary before `offset`,
+	/// i.e. the start of the segment containing the character before `offset`.
+	///
+	/// The result lies between 0 and `offset`, inclusive,
+	/// so it can be used to move the caret without stepping out of range.
+	///
+	/// # Errors
+	///
+	/// If the [`character_count`] or [`get_string_at_offset`] call fails.
+	///
+	/// [`character_count`]: #method.character_count
+	/// [`get_string_at_offset`]: #method.get_string_at_offset
+	pub async fn prev_boundary(&self, offset: i32, granularity: Granularity) -> zbus::Result<i32> {
+		let count = self.character_count().await?.max(0);
+		let offset = offset.clamp(0, count);
+		if offset == 0 {
+			return Ok(0);
+		}
+		let (_, start, _) = self.get_string_at_offset(offset - 1, granularity).await?;
+		Ok(start.clamp(0, offset))
+	}
+
+	/// Request the caret to move to `offset`, treating negative offsets as 0.
+	///
+	/// Returns `true` if the application moved the caret.
+	///
+	/// # Errors
+	///
+	/// If the [`set_caret_offset`] call fails.
+	///
+	/// [`set_caret_offset`]: #method.set_caret_offset
+	pub async fn set_caret_offset_clamped(&self, offset: i32) -> zbus::Result<bool> {
+		self.set_caret_offset(offset.max(0)).await
+	}
+
+	/// Returns the segment of the given `granularity` around the caret, e.g. the word or line it is in,
+	/// as `(text, start_offset, end_offset)`.
+	///
+	/// # Errors
+	///
+	/// - If the [`caret_offset`] or [`get_string_at_offset`] call fails.
+	/// - If the object has no caret, i.e. its caret offset is -1.
+	///
+	/// [`caret_offset`]: #method.caret_offset
+	/// [`get_string_at_offset`]: #method.get_string_at_offset
+	pub async fn caret_context(
+		&self,
+		granularity: Granularity,
+	) -> zbus::Result<(String, i32, i32)> {
+		let caret = self.caret_offset().await?;
+		if caret < 0 {
+			return Err(zbus::Error::Failure("text has no caret".into()));
+		}
+		self.get_string_at_offset(caret, granularity).await
+	}
+
+	/// Walks through all of the text, yielding each segment of the given `granularity`
+	/// as `(text, start_offset, end_offset)`, e.g. word by word or line by line.
+	///
+	/// Each segment is requested with [`get_string_at_offset`] at the end offset of the previous one.
+	/// The stream ends at the end of the text, or at the first segment which does not extend past
+	/// the requested offset, as returned by applications which do not support the requested granularity.
+	///
+	/// The stream ends after yielding the first error.
+	///
+	/// [`get_string_at_offset`]: #method.get_string_at_offset
+	pub fn segments(
+		&self,
+		granularity: Granularity,
+	) -> impl Stream<Item = zbus::Result<(String, i32, i32)>> + '_ {
+		stream::unfold(Some((0, None)), move |state| async move {
+			let (offset, count) = state?;
+			let count = match count {
+				Some(count) => count,
+				None => match self.character_count().await {
+					Ok(count) => count,
+					Err(e) => return Some((Err(e), None)),
+				},
+			};
+			if offset >= count {
+				return None;
+			}
+			match self.get_string_at_offset(offset, granularity).await {
+				Ok((text, start, end)) if end > offset => {
+					Some((Ok((text, start, end)), Some((end, Some(count)))))
+				}
+				Ok(_) => None,
+				Err(e) => Some((Err(e), None)),
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{TextProxy, TextRange};
+	use crate::common::{ClipType, CoordType, Granularity, Rect};
+	use crate::serve_mock;
+	use futures_lite::StreamExt;
+	use std::collections::HashMap;
+	use zbus::zvariant::OwnedValue;
+
+	const CHAR_WIDTH: i32 = 10;
+	const LINE_HEIGHT: i32 = 20;
+	/// The first five characters of the text are bold.
+	const BOLD_END: i32 = 5;
+
+	/// Lays out its lines top to bottom, with fixed width characters.
+	struct MockText {
+		lines: Vec<&'static str>,
+		caret_offset: i32,
+		/// The number of `GetStringAtOffset` calls made.
+		string_requests: u32,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Text")]
+	impl MockText {
+		/// Only screen coordinates and unclipped ranges are supported.
+		fn get_bounded_ranges(
+			&self,
+			x: i32,
+			y: i32,
+			width: i32,
+			height: i32,
+			coord_type: u32,
+			x_clip_type: u32,
+			y_clip_type: u32,
+		) -> Vec<(i32, i32, String, OwnedValue)> {
+			let unclipped = ClipType::Neither as u32;
+			if coord_type != CoordType::Screen as u32
+				|| x_clip_type != unclipped
+				|| y_clip_type != unclipped
+			{
+				return Vec::new();
+			}
+			let viewport = Rect::new(x, y, width, height);
+			let mut ranges = Vec::new();
+			let mut line_offset = 0;
+			for (row, line) in (0..).zip(&self.lines) {
+				let visible: Vec<(i32, char)> = (0..)
+					.zip(line.chars())
+					.filter(|(col, _)| {
+						let glyph =
+							Rect::new(col * CHAR_WIDTH, row * LINE_HEIGHT, CHAR_WIDTH, LINE_HEIGHT);
+						glyph.intersects(&viewport)
+					})
+					.collect();
+				if let (Some((first, _)), Some((last, _))) = (visible.first(), visible.last()) {
+					let text = visible.iter().map(|(_, ch)| ch).collect();
+					ranges.push((
+						line_offset + first,
+						line_offset + last + 1,
+						text,
+						OwnedValue::from(0),
+					));
+				}
+				line_offset += i32::try_from(line.chars().count()).unwrap() + 1;
+			}
+			ranges
+		}
+
+		/// Characters, including newlines, are laid out in a grid; the end of the text has no extents.
+		fn get_character_extents(&self, offset: i32, coord_type: u32) -> (i32, i32, i32, i32) {
+			if offset >= self.character_count() {
+				return (0, 0, 0, 0);
+			}
+			let mut line_offset = 0;
+			for (row, line) in (0..).zip(&self.lines) {
+				let line_end = line_offset + i32::try_from(line.chars().count()).unwrap();
+				if coord_type == CoordType::Screen as u32 && offset <= line_end {
+					let col = offset - line_offset;
+					return (col * CHAR_WIDTH, row * LINE_HEIGHT, CHAR_WIDTH, LINE_HEIGHT);
+				}
+				line_offset = line_end + 1;
+			}
+			(0, 0, 0, 0)
+		}
+
+		/// The inverse of `get_character_extents`, excluding newlines.
+		fn get_offset_at_point(&self, x: i32, y: i32, coord_type: u32) -> i32 {
+			if coord_type != CoordType::Screen as u32 || x < 0 || y < 0 {
+				return -1;
+			}
+			let (row, col) = (y / LINE_HEIGHT, x / CHAR_WIDTH);
+			let mut line_offset = 0;
+			for (line_row, line) in (0..).zip(&self.lines) {
+				let len = i32::try_from(line.chars().count()).unwrap();
+				if line_row == row {
+					return if col < len { line_offset + col } else { -1 };
+				}
+				line_offset += len + 1;
+			}
+			-1
+		}
+
+		/// The text up to [`BOLD_END`] is bold; all text uses the same font family by default.
+		fn get_attribute_run(
+			&self,
+			offset: i32,
+			include_defaults: bool,
+		) -> (HashMap<String, String>, i32, i32) {
+			let mut attributes = HashMap::new();
+			if include_defaults {
+				attributes.insert("family-name".to_owned(), "Cantarell".to_owned());
+			}
+			if offset < BOLD_END {
+				attributes.insert("weight".to_owned(), "bold".to_owned());
+				(attributes, 0, BOLD_END)
+			} else {
+				(attributes, BOLD_END, self.character_count())
+			}
+		}
+
+		/// Rejects empty ranges, which callers should not need to request.
+		fn get_text(&self, start_offset: i32, end_offset: i32) -> zbus::fdo::Result<String> {
+			let (Ok(start), Ok(end)) = (usize::try_from(start_offset), usize::try_from(end_offset))
+			else {
+				return Err(zbus::fdo::Error::InvalidArgs("negative offset".into()));
+			};
+			if start >= end {
+				return Err(zbus::fdo::Error::InvalidArgs("empty range".into()));
+			}
+			Ok(self.lines.join("\n").chars().skip(start).take(end - start).collect())
+		}
+
+		#[zbus(property)]
+		fn caret_offset(&self) -> i32 {
+			self.caret_offset
+		}
+
+		/// Only offsets within the text, including its end, are accepted.
+		fn set_caret_offset(&mut self, offset: i32) -> bool {
+			let count = i32::try_from(self.lines.join("\n").chars().count()).unwrap();
+			if !(0..=count).contains(&offset) {
+				return false;
+			}
+			self.caret_offset = offset;
+			true
+		}
+
+		/// Supports characters, words including their trailing whitespace, and lines including their newline.
+		/// Other granularities yield zero-width segments.
+		fn get_string_at_offset(&mut self, offset: i32, granularity: u32) -> (String, i32, i32) {
+			self.string_requests += 1;
+			let text: Vec<char> = self.lines.join("\n").chars().collect();
+			let Some(offset) = usize::try_from(offset).ok().filter(|&offset| offset < text.len())
+			else {
+				return (String::new(), -1, -1);
+			};
+			let (start, end) = if granularity == Granularity::Char as u32 {
+				(offset, offset + 1)
+			} else if granularity == Granularity::Word as u32 {
+				let is_word_start =
+					|i: usize| i == 0 || (!text[i].is_whitespace() && text[i - 1].is_whitespace());
+				let start = (0..=offset).rev().find(|&i| is_word_start(i)).unwrap();
+				let end = (offset + 1..text.len())
+					.find(|&i| is_word_start(i))
+					.unwrap_or(text.len());
+				(start, end)
+			} else if granularity == Granularity::Line as u32 {
+				let is_line_start = |i: usize| i == 0 || text[i - 1] == '\n';
+				let start = (0..=offset).rev().find(|&i| is_line_start(i)).unwrap();
+				let end = (offset + 1..text.len())
+					.find(|&i| is_line_start(i))
+					.unwrap_or(text.len());
+				(start, end)
+			} else {
+				(offset, offset)
+			};
+			let segment = text[start..end].iter().collect();
+			(segment, start.try_into().unwrap(), end.try_into().unwrap())
+		}
+
+		#[zbus(property)]
+		fn character_count(&self) -> i32 {
+			self.lines.join("\n").chars().count().try_into().unwrap()
+		}
+	}
+
+	async fn mock_text() -> (zbus::Connection, TextProxy<'static>) {
+		mock_text_of(vec!["first line", "second line", "third line"]).await
+	}
+
+	async fn mock_text_of(lines: Vec<&'static str>) -> (zbus::Connection, TextProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/mock";
+		let mock = MockText { lines, caret_offset: 0, string_requests: 0 };
+		serve_mock(path, mock).await
+	}
+
+	#[tokio::test]
+	async fn visible_range_of_partially_covered_text() {
+		let (_conn, text) = mock_text().await;
+		// Covers "secon" on the second line through "third" on the third.
+		// The span runs from the first to the last of these, including the hidden "d line\n".
+		let viewport = Rect::new(0, 30, 50, 40);
+		assert_eq!(text.visible_range(viewport, CoordType::Screen).await.unwrap(), (11, 28));
+	}
+
+	#[tokio::test]
+	async fn visible_range_outside_text_is_empty() {
+		let (_conn, text) = mock_text().await;
+		let viewport = Rect::new(0, 100, 50, 40);
+		assert_eq!(text.visible_range(viewport, CoordType::Screen).await.unwrap(), (0, 0));
+	}
+
+	#[tokio::test]
+	async fn whole_text_of_multiple_lines() {
+		let (_conn, text) = mock_text().await;
+		assert_eq!(text.get_whole_text().await.unwrap(), "first line\nsecond line\nthird line");
+	}
+
+	#[tokio::test]
+	async fn whole_text_of_empty_text() {
+		let (_conn, text) = mock_text_of(vec![]).await;
+		assert_eq!(text.get_whole_text().await.unwrap(), "");
+	}
+
+	#[tokio::test]
+	async fn segments_of_words() {
+		let (_conn, text) = mock_text_of(vec!["hello world"]).await;
+		let words: Vec<_> = text.segments(Granularity::Word).try_collect().await.unwrap();
+		assert_eq!(words, [("hello ".to_string(), 0, 6), ("world".to_string(), 6, 11)]);
+	}
+
+	#[tokio::test]
+	async fn segments_of_empty_text() {
+		let (_conn, text) = mock_text_of(vec![]).await;
+		assert_eq!(text.segments(Granularity::Word).count().await, 0);
+	}
+
+	#[tokio::test]
+	async fn segments_of_unsupported_granularity() {
+		let (conn, text) = mock_text_of(vec!["hello world"]).await;
+		assert_eq!(text.segments(Granularity::Sentence).count().await, 0);
+		let mock = conn
+			.object_server()
+			.interface::<_, MockText>("/org/a11y/atspi/accessible/mock")
+			.await
+			.unwrap();
+		assert_eq!(mock.get().await.string_requests, 1);
+	}
+
+	#[tokio::test]
+	async fn word_boundaries() {
+		let (_conn, text) = mock_text_of(vec!["hello world", "second line"]).await;
+		let next = |offset| text.next_boundary(offset, Granularity::Word);
+		assert_eq!(next(0).await.unwrap(), 6);
+		assert_eq!(next(6).await.unwrap(), 12);
+		assert_eq!(next(20).await.unwrap(), 23);
+		assert_eq!(next(23).await.unwrap(), 23);
+		assert_eq!(next(100).await.unwrap(), 23);
+		let prev = |offset| text.prev_boundary(offset, Granularity::Word);
+		assert_eq!(prev(8).await.unwrap(), 6);
+		assert_eq!(prev(6).await.unwrap(), 0);
+		assert_eq!(prev(12).await.unwrap(), 6);
+		assert_eq!(prev(0).await.unwrap(), 0);
+		assert_eq!(prev(-5).await.unwrap(), 0);
+	}
+
+	#[tokio::test]
+	async fn line_boundaries() {
+		let (_conn, text) = mock_text_of(vec!["hello world", "second line"]).await;
+		let next = |offset| text.next_boundary(offset, Granularity::Line);
+		assert_eq!(next(3).await.unwrap(), 12);
+		assert_eq!(next(12).await.unwrap(), 23);
+		let prev = |offset| text.prev_boundary(offset, Granularity::Line);
+		assert_eq!(prev(15).await.unwrap(), 12);
+		assert_eq!(prev(12).await.unwrap(), 0);
+		assert_eq!(prev(23).await.unwrap(), 12);
+	}
+
+	#[tokio::test]
+	async fn attributes_of_bold_run() {
+		let (_conn, text) = mock_text().await;
+		let bold = HashMap::from([("weight".to_owned(), "bold".to_owned())]);
+		assert_eq!(text.get_attribute_run(2, false).await.unwrap(), (bold, 0, 5));
+	}
+
+	#[tokio::test]
+	async fn attributes_include_defaults_on_request() {
+		let (_conn, text) = mock_text().await;
+		let family = ("family-name".to_owned(), "Cantarell".to_owned());
+		let bold = ("weight".to_owned(), "bold".to_owned());
+		let (attributes, start, end) = text.get_attribute_run(0, true).await.unwrap();
+		assert_eq!(attributes, HashMap::from([family.clone(), bold]));
+		assert_eq!((start, end), (0, 5));
+		let (attributes, start, end) = text.get_attribute_run(7, true).await.unwrap();
+		assert_eq!(attributes, HashMap::from([family]));
+		assert_eq!((start, end), (5, 33));
+		assert!(text.get_attribute_run(7, false).await.unwrap().0.is_empty());
+	}
+
+	#[tokio::test]
+	async fn bounded_ranges_within_one_line() {
+		let (_conn, text) = mock_text().await;
+		// Covers "second", partially covering its last character.
+		let area = Rect::new(0, 25, 55, 10);
+		let ranges = text
+			.bounded_ranges(area, CoordType::Screen, ClipType::Neither, ClipType::Neither)
+			.await
+			.unwrap();
+		assert_eq!(ranges, [TextRange { start: 11, end: 17, content: "second".to_owned() }]);
+	}
+
+	#[tokio::test]
+	async fn character_extents_of_first_character() {
+		let (_conn, text) = mock_text().await;
+		let extents = text.character_extents(0, CoordType::Screen).await.unwrap();
+		assert_eq!(extents, Rect::new(0, 0, CHAR_WIDTH, LINE_HEIGHT));
+		let extents = text.character_extents(12, CoordType::Screen).await.unwrap();
+		assert_eq!(extents, Rect::new(CHAR_WIDTH, LINE_HEIGHT, CHAR_WIDTH, LINE_HEIGHT));
+	}
+
+	#[tokio::test]
+	async fn character_extents_at_end_of_text() {
+		let (_conn, text) = mock_text().await;
+		let end = text.character_count().await.unwrap();
+		let extents = text.character_extents(end, CoordType::Screen).await.unwrap();
+		// Right after "third line".
+		assert_eq!(extents, Rect::new(10 * CHAR_WIDTH, 2 * LINE_HEIGHT, 0, LINE_HEIGHT));
+	}
+
+	#[tokio::test]
+	async fn get_offset_at_point_within_and_outside_text() {
+		let (_conn, text) = mock_text().await;
+		let offset = text.get_offset_at_point(15, 25, CoordType::Screen).await.unwrap();
+		assert_eq!(offset, 12);
+		let extents = text.character_extents(offset, CoordType::Screen).await.unwrap();
+		assert!((extents.x..extents.x + extents.width).contains(&15));
+		assert!((extents.y..extents.y + extents.height).contains(&25));
+
+		// Right of "first line", and below the last line.
+		assert_eq!(text.get_offset_at_point(150, 5, CoordType::Screen).await.unwrap(), -1);
+		assert_eq!(text.get_offset_at_point(5, 100, CoordType::Screen).await.unwrap(), -1);
+	}
+
+	async fn set_caret(conn: &zbus::Connection, offset: i32) {
+		let mock = conn
+			.object_server()
+			.interface::<_, MockText>("/org/a11y/atspi/accessible/mock")
+			.await
+			.unwrap();
+		mock.get_mut().await.caret_offset = offset;
+	}
+
+	#[tokio::test]
+	async fn caret_context_by_word_and_line() {
+		let (conn, text) = mock_text().await;
+		set_caret(&conn, 6).await;
+		let word = text.caret_context(Granularity::Word).await.unwrap();
+		assert_eq!(word, ("line\n".to_owned(), 6, 11));
+		let line = text.caret_context(Granularity::Line).await.unwrap();
+		assert_eq!(line, ("first line\n".to_owned(), 0, 11));
+	}
+
+	#[tokio::test]
+	async fn caret_context_without_caret() {
+		let (conn, text) = mock_text().await;
+		set_caret(&conn, -1).await;
+		assert!(matches!(
+			text.caret_context(Granularity::Word).await,
+			Err(zbus::Error::Failure(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn get_and_set_caret_offset() {
+		let (_conn, text) = mock_text().await;
+		assert_eq!(text.caret_offset().await.unwrap(), 0);
+		assert!(text.set_caret_offset(12).await.unwrap());
+		assert_eq!(text.caret_offset().await.unwrap(), 12);
+
+		assert!(!text.set_caret_offset(-5).await.unwrap());
+		assert_eq!(text.caret_offset().await.unwrap(), 12);
+		assert!(text.set_caret_offset_clamped(-5).await.unwrap());
+		assert_eq!(text.caret_offset().await.unwrap(), 0);
+	}
+}
@@ -0,0 +1,225 @@
+//! Helpers to locate an [`AccessibleProxy`] within the accessibility tree.
+
+use crate::accessible::{AccessibleProxy, ObjectRefExt};
+use crate::common::ObjectRef;
+use crate::AtspiError;
+use std::collections::HashSet;
+
+/// The maximum number of levels the traversal helpers walk up or down the tree.
+///
+/// Accessibility trees are rarely more than a few dozen levels deep;
+/// hitting this limit usually means the tree is malformed.
+pub const MAX_TREE_DEPTH: usize = 1024;
+
+impl AccessibleProxy<'_> {
+	/// Returns the path of child indices leading from the root to `self`, root-first.
+	///
+	/// For example, `[0, 3, 1]` is the second child of the fourth child of the root's first child.
+	/// The walk ends at the root, the first object whose parent is null, see [`ObjectRef::is_null`],
+	/// so the root itself has an empty path.
+	/// Unlike an accessible id, this works for any object, but it is only stable as long as the tree does not change.
+	///
+	/// # Errors
+	///
+	/// - If any of the `DBus` calls fail.
+	/// - If an object which has a parent reports a negative index in it,
+	///   as transient objects like popup menus may do.
+	/// - If an ancestor is encountered twice, i.e. the tree contains a cycle.
+	/// - If the tree is deeper than [`MAX_TREE_DEPTH`].
+	pub async fn index_path(&self) -> Result<Vec<i32>, AtspiError> {
+		let conn = self.inner().connection();
+		let mut path = Vec::new();
+		let mut visited = HashSet::from([ObjectRef::try_from(self)?]);
+		let mut current = self.clone();
+		loop {
+			let parent = current.parent().await?;
+			if parent.is_null() {
+				break;
+			}
+			if path.len() >= MAX_TREE_DEPTH {
+				return Err(AtspiError::Owned(format!(
+					"accessibility tree is deeper than {MAX_TREE_DEPTH} levels"
+				)));
+			}
+			let index = current.get_index_in_parent().await?;
+			if index < 0 {
+				return Err(AtspiError::Owned(format!(
+					"{}:{} has a parent, but no index in it",
+					current.inner().destination(),
+					current.inner().path()
+				)));
+			}
+			if !visited.insert(parent.clone()) {
+				return Err(AtspiError::Owned(format!(
+					"cycle in accessibility tree at {}:{}",
+					parent.name, parent.path
+				)));
+			}
+			path.push(index);
+			current = parent.into_accessible_proxy(conn).await?;
+		}
+		path.reverse();
+		Ok(path)
+	}
+
+	/// Descends from `self` through the children at the given indices, the inverse of [`AccessibleProxy::index_path`].
+	///
+	/// Call this on the root the path was taken from; an empty path resolves to `self`.
+	///
+	/// Returns `None` if any index is out of range, e.g. because the tree changed since the path was taken.
+	///
+	/// # Errors
+	///
+	/// If any of the `DBus` calls fail.
+	pub async fn resolve_index_path(
+		&self,
+		path: &[i32],
+	) -> Result<Option<AccessibleProxy<'_>>, AtspiError> {
+		let conn = self.inner().connection();
+		let mut current = self.clone();
+		for &index in path {
+			if index < 0 || index >= current.child_count().await? {
+				return Ok(None);
+			}
+			let child = current.get_child_at_index(index).await?;
+			current = child.into_accessible_proxy(conn).await?;
+		}
+		Ok(Some(current))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::accessible::{AccessibleProxy, ObjectRefExt};
+	use crate::common::ObjectRef;
+
+	/// A node in a mock accessibility tree.
+	struct MockNode {
+		parent: ObjectRef,
+		index_in_parent: i32,
+		children: Vec<ObjectRef>,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Accessible")]
+	impl MockNode {
+		fn get_index_in_parent(&self) -> i32 {
+			self.index_in_parent
+		}
+
+		fn get_child_at_index(&self, index: i32) -> ObjectRef {
+			usize::try_from(index)
+				.ok()
+				.and_then(|index| self.children.get(index))
+				.cloned()
+				.unwrap_or_default()
+		}
+
+		#[zbus(property)]
+		fn child_count(&self) -> i32 {
+			self.children.len().try_into().unwrap()
+		}
+
+		#[zbus(property)]
+		fn parent(&self) -> ObjectRef {
+			self.parent.clone()
+		}
+	}
+
+	/// Serves a three-level tree below a root, and returns the connection serving it.
+	///
+	/// ```text
+	/// root
+	/// ├── (2 unserved siblings)
+	/// └── app:    index 2
+	///     └── panel:  index 0
+	///         ├── (1 unserved sibling)
+	///         └── button: index 1
+	/// ```
+	async fn mock_tree() -> zbus::Connection {
+		let conn = zbus::Connection::session().await.unwrap();
+		let obj = |name: &str| ObjectRef {
+			name: conn.unique_name().unwrap().clone(),
+			path: path(name).try_into().unwrap(),
+		};
+		let nodes = [
+			("root", ObjectRef::default(), -1, vec![obj("label"), obj("menu"), obj("app")]),
+			("app", obj("root"), 2, vec![obj("panel")]),
+			("panel", obj("app"), 0, vec![obj("icon"), obj("button")]),
+			("button", obj("panel"), 1, vec![]),
+		];
+		for (name, parent, index_in_parent, children) in nodes {
+			let node = MockNode { parent, index_in_parent, children };
+			conn.object_server().at(path(name), node).await.unwrap();
+		}
+		conn
+	}
+
+	fn path(name: &str) -> String {
+		format!("/org/a11y/atspi/accessible/{name}")
+	}
+
+	async fn node<'c>(conn: &'c zbus::Connection, name: &str) -> AccessibleProxy<'c> {
+		let obj = ObjectRef {
+			name: conn.unique_name().unwrap().clone(),
+			path: path(name).try_into().unwrap(),
+		};
+		obj.into_accessible_proxy(conn).await.unwrap()
+	}
+
+	#[tokio::test]
+	async fn index_path_of_nested_node() {
+		let conn = mock_tree().await;
+		assert_eq!(node(&conn, "button").await.index_path().await.unwrap(), [2, 0, 1]);
+		assert_eq!(node(&conn, "app").await.index_path().await.unwrap(), [2]);
+		assert!(node(&conn, "root").await.index_path().await.unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn index_path_detects_cycles() {
+		let conn = mock_tree().await;
+		let loop_back = MockNode {
+			parent: ObjectRef {
+				name: conn.unique_name().unwrap().clone(),
+				path: path("cycle").try_into().unwrap(),
+			},
+			index_in_parent: 0,
+			children: vec![],
+		};
+		conn.object_server().at(path("cycle"), loop_back).await.unwrap();
+		assert!(node(&conn, "cycle").await.index_path().await.is_err());
+	}
+
+	#[tokio::test]
+	async fn index_path_rejects_missing_index_below_a_parent() {
+		let conn = mock_tree().await;
+		let popup = MockNode {
+			parent: ObjectRef {
+				name: conn.unique_name().unwrap().clone(),
+				path: path("panel").try_into().unwrap(),
+			},
+			index_in_parent: -1,
+			children: vec![],
+		};
+		conn.object_server().at(path("popup"), popup).await.unwrap();
+		assert!(node(&conn, "popup").await.index_path().await.is_err());
+	}
+
+	#[tokio::test]
+	async fn resolve_valid_index_path() {
+		let conn = mock_tree().await;
+		let root = node(&conn, "root").await;
+		let button = root.resolve_index_path(&[2, 0, 1]).await.unwrap().unwrap();
+		assert_eq!(button, node(&conn, "button").await);
+		assert_eq!(button.index_path().await.unwrap(), [2, 0, 1]);
+		assert_eq!(root.resolve_index_path(&[]).await.unwrap().unwrap(), root);
+	}
+
+	#[tokio::test]
+	async fn resolve_out_of_range_index_path() {
+		let conn = mock_tree().await;
+		let root = node(&conn, "root").await;
+		assert!(root.resolve_index_path(&[3]).await.unwrap().is_none());
+		assert!(root.resolve_index_path(&[2, 0, 2]).await.unwrap().is_none());
+		assert!(root.resolve_index_path(&[2, -1]).await.unwrap().is_none());
+	}
+}
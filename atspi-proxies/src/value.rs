@@ -10,6 +10,12 @@
 //! section of the zbus documentation.
 //!
 
+use crate::AtspiError;
+
+/// Fraction of the value's range used as a step by [`ValueProxy::increment`] and
+/// [`ValueProxy::decrement`] when the object does not report a minimum increment.
+pub const DEFAULT_STEP_FRACTION: f64 = 0.05;
+
 #[zbus::proxy(interface = "org.a11y.atspi.Value", assume_defaults = true)]
 trait Value {
 	/// CurrentValue property
@@ -32,3 +38,170 @@ trait Value {
 	#[zbus(property)]
 	fn minimum_value(&self) -> zbus::Result<f64>;
 }
+
+impl ValueProxy<'_> {
+	/// Increase the current value by the minimum increment, clamped to the maximum value.
+	///
+	/// If the minimum increment is zero or not reported, the step is
+	/// [`DEFAULT_STEP_FRACTION`] of the range between minimum and maximum value.
+	///
+	/// Returns the new value.
+	///
+	/// # Errors
+	///
+	/// - If reading the current, minimum or maximum value fails, or if setting the new value fails.
+	/// - If the minimum or maximum value is NaN, or the minimum value is greater than the maximum value.
+	pub async fn increment(&self) -> Result<f64, AtspiError> {
+		self.step_by(1.0).await
+	}
+
+	/// Decrease the current value by the minimum increment, clamped to the minimum value.
+	///
+	/// If the minimum increment is zero or not reported, the step is
+	/// [`DEFAULT_STEP_FRACTION`] of the range between minimum and maximum value.
+	///
+	/// Returns the new value.
+	///
+	/// # Errors
+	///
+	/// - If reading the current, minimum or maximum value fails, or if setting the new value fails.
+	/// - If the minimum or maximum value is NaN, or the minimum value is greater than the maximum value.
+	pub async fn decrement(&self) -> Result<f64, AtspiError> {
+		self.step_by(-1.0).await
+	}
+
+	/// Set the current value to `value`, clamped to the range between minimum and maximum value.
+	///
+	/// Returns the value actually set.
+	///
+	/// # Errors
+	///
+	/// - If reading the minimum or maximum value fails, or if setting the new value fails.
+	/// - If the minimum or maximum value is NaN, or the minimum value is greater than the maximum value.
+	pub async fn set_current_value_clamped(&self, value: f64) -> Result<f64, AtspiError> {
+		let (minimum, maximum) = self.range().await?;
+		let value = value.clamp(minimum, maximum);
+		self.set_current_value(value).await?;
+		Ok(value)
+	}
+
+	async fn step_by(&self, direction: f64) -> Result<f64, AtspiError> {
+		let current = self.current_value().await?;
+		let (minimum, maximum) = self.range().await?;
+		let increment = self.minimum_increment().await.unwrap_or(0.0);
+		let step =
+			if increment > 0.0 { increment } else { (maximum - minimum) * DEFAULT_STEP_FRACTION };
+		let value = (current + direction * step).clamp(minimum, maximum);
+		self.set_current_value(value).await?;
+		Ok(value)
+	}
+
+	/// The minimum and maximum value, checked to form a range which values can be clamped to.
+	async fn range(&self) -> Result<(f64, f64), AtspiError> {
+		let minimum = self.minimum_value().await?;
+		let maximum = self.maximum_value().await?;
+		if minimum.is_nan() || maximum.is_nan() || minimum > maximum {
+			return Err(AtspiError::Owned(format!(
+				"minimum value {minimum} and maximum value {maximum} do not form a range"
+			)));
+		}
+		Ok((minimum, maximum))
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+	use super::ValueProxy;
+	use crate::serve_mock;
+
+	struct MockValue {
+		current: f64,
+		minimum: f64,
+		maximum: f64,
+		increment: f64,
+	}
+
+	#[zbus::interface(name = "org.a11y.atspi.Value")]
+	impl MockValue {
+		#[zbus(property)]
+		fn current_value(&self) -> f64 {
+			self.current
+		}
+
+		#[zbus(property)]
+		fn set_current_value(&mut self, value: f64) {
+			self.current = value;
+		}
+
+		#[zbus(property)]
+		fn maximum_value(&self) -> f64 {
+			self.maximum
+		}
+
+		#[zbus(property)]
+		fn minimum_increment(&self) -> f64 {
+			self.increment
+		}
+
+		#[zbus(property)]
+		fn minimum_value(&self) -> f64 {
+			self.minimum
+		}
+	}
+
+	async fn mock_value(mock: MockValue) -> (zbus::Connection, ValueProxy<'static>) {
+		let path = "/org/a11y/atspi/accessible/mock";
+		serve_mock(path, mock).await
+	}
+
+	#[tokio::test]
+	async fn increment_steps_up_and_clamps_at_maximum() {
+		let mock = MockValue { current: 8.0, minimum: 0.0, maximum: 10.0, increment: 1.5 };
+		let (_conn, value) = mock_value(mock).await;
+		assert_eq!(value.increment().await.unwrap(), 9.5);
+		assert_eq!(value.increment().await.unwrap(), 10.0);
+		assert_eq!(value.current_value().await.unwrap(), 10.0);
+		assert_eq!(value.decrement().await.unwrap(), 8.5);
+	}
+
+	#[tokio::test]
+	async fn zero_increment_steps_by_fraction_of_range() {
+		let mock = MockValue { current: 0.0, minimum: 0.0, maximum: 200.0, increment: 0.0 };
+		let (_conn, value) = mock_value(mock).await;
+		assert_eq!(value.increment().await.unwrap(), 10.0);
+		assert_eq!(value.decrement().await.unwrap(), 0.0);
+		assert_eq!(value.decrement().await.unwrap(), 0.0);
+	}
+
+	#[tokio::test]
+	async fn set_current_value_clamps_to_range() {
+		let mock = MockValue { current: 5.0, minimum: -1.0, maximum: 10.0, increment: 1.0 };
+		let (_conn, value) = mock_value(mock).await;
+		assert_eq!(value.set_current_value_clamped(12.5).await.unwrap(), 10.0);
+		assert_eq!(value.current_value().await.unwrap(), 10.0);
+		assert_eq!(value.set_current_value_clamped(-3.0).await.unwrap(), -1.0);
+		assert_eq!(value.current_value().await.unwrap(), -1.0);
+		assert_eq!(value.set_current_value_clamped(2.5).await.unwrap(), 2.5);
+	}
+
+	#[tokio::test]
+	async fn set_current_value_rejects_inverted_range() {
+		let mock = MockValue { current: 5.0, minimum: 10.0, maximum: 0.0, increment: 1.0 };
+		let (_conn, value) = mock_value(mock).await;
+		assert!(value.set_current_value_clamped(5.0).await.is_err());
+		assert_eq!(value.current_value().await.unwrap(), 5.0);
+	}
+
+	#[tokio::test]
+	async fn nan_bound_is_rejected() {
+		for (minimum, maximum) in [(f64::NAN, 10.0), (0.0, f64::NAN)] {
+			let mock = MockValue { current: 5.0, minimum, maximum, increment: 1.0 };
+			let (_conn, value) = mock_value(mock).await;
+			assert!(value.set_current_value_clamped(5.0).await.is_err());
+			assert!(value.increment().await.is_err());
+			assert!(value.decrement().await.is_err());
+			assert_eq!(value.current_value().await.unwrap(), 5.0);
+		}
+	}
+}